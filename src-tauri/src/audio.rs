@@ -1,13 +1,18 @@
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::Mutex;
 use std::thread;
+use std::time::Instant;
+use std::collections::VecDeque;
 use hound::{WavSpec, WavWriter};
 use std::io::BufWriter;
 use std::fs::File;
 use serde_json::json;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 
+use crate::levels::{self, ChannelLevel};
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RecordingState {
     Idle,
@@ -15,12 +20,121 @@ pub enum RecordingState {
     Paused,
 }
 
+/// Outcome of `AudioRecorder::stop_recording`: either a usable recording, or
+/// a signal that nothing worth keeping was captured (mic muted/unavailable,
+/// pure silence for the whole session) so the caller can skip treating this
+/// as a normal meeting instead of saving a zero-byte artifact.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "status")]
+pub enum RecordingOutcome {
+    Recorded { meeting_id: String, duration_secs: u64 },
+    NoAudioCaptured { meeting_id: String },
+}
+
+/// One input device as enumerated by `list_audio_devices`. `is_monitor`
+/// flags a loopback/output-monitor source (named `*.monitor` on PulseAudio
+/// setups) so callers can offer it separately from real microphones, the way
+/// `set_dual_audio_config`/`get_audio_devices_by_type` already split them.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AudioDevice {
+    pub index: usize,
+    pub name: String,
+    pub is_monitor: bool,
+    /// Sample rates (Hz) drawn from the device's supported input config
+    /// ranges, deduped and sorted; empty if the driver reported none.
+    pub supported_sample_rates: Vec<u32>,
+}
+
+/// Devices actually opened for the most recent recording, surfaced through
+/// `AudioRecorder::get_state` for diagnostics when a recording sounds wrong
+/// (e.g. to confirm which physical mic a saved device index resolved to).
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct DevicesUsed {
+    pub mic_device: Option<String>,
+    pub loopback_device: Option<String>,
+}
+
+fn get_device_by_index(index: usize) -> anyhow::Result<cpal::Device> {
+    let host = cpal::default_host();
+    host.input_devices()?
+        .nth(index)
+        .ok_or_else(|| anyhow::anyhow!("Device with index {} not found", index))
+}
+
+fn device_sample_rates(device: &cpal::Device) -> Vec<u32> {
+    let mut rates: Vec<u32> = device
+        .supported_input_configs()
+        .map(|configs| {
+            configs
+                .flat_map(|c| [c.min_sample_rate().0, c.max_sample_rate().0])
+                .collect()
+        })
+        .unwrap_or_default();
+    rates.sort_unstable();
+    rates.dedup();
+    rates
+}
+
+/// Enumerates every input device the host exposes, including loopback/output
+/// monitor sources, for device pickers and for `dual_audio`'s default-device
+/// detection.
+pub fn list_audio_devices() -> anyhow::Result<Vec<AudioDevice>> {
+    let host = cpal::default_host();
+    let devices = host
+        .input_devices()?
+        .enumerate()
+        .map(|(index, device)| {
+            let name = device.name().unwrap_or_else(|_| "Unknown device".to_string());
+            let is_monitor = name.to_lowercase().contains("monitor");
+            let supported_sample_rates = device_sample_rates(&device);
+            AudioDevice { index, name, is_monitor, supported_sample_rates }
+        })
+        .collect();
+
+    Ok(devices)
+}
+
 pub struct AudioRecorder {
     audio_dir: PathBuf,
     state: RecordingState,
     current_meeting_id: Option<String>,
     sample_rate: u32,
     is_recording: Arc<AtomicBool>,
+    /// Gates the capture callbacks separately from `is_recording`: while
+    /// paused the stream keeps running (so resume is instant) but every
+    /// frame is dropped before resampling/writing, so the WAV file itself
+    /// comes out gapless instead of containing silence for the pause.
+    is_paused: Arc<AtomicBool>,
+    /// Target-rate sample offset into the (gapless) output file at which
+    /// each pause began, so the frontend can draw gap markers on the
+    /// meeting timeline.
+    pause_markers: Arc<Mutex<Vec<u64>>>,
+    /// Count of samples actually written to the WAV file so far, at
+    /// `sample_rate` - the basis for `pause_markers` offsets.
+    samples_written: Arc<AtomicU64>,
+    /// Wall-clock time the current pause began, if paused.
+    pause_started_at: Arc<Mutex<Option<Instant>>>,
+    /// Total wall-clock time spent paused this recording.
+    paused_duration_secs: Arc<Mutex<f64>>,
+    input_gain: Arc<AtomicU32>,
+    clip_hold_ms: u64,
+    levels: Arc<ChannelLevel>,
+    silence_threshold: f32,
+    auto_pause_secs: f32,
+    /// RMS amplitude, across the whole finalized recording, below which
+    /// `stop_recording` treats it as no audio captured rather than a real
+    /// (if quiet) meeting.
+    min_audio_rms: f32,
+    /// Input device to open for the mic stream; `None` uses the host's
+    /// default input device.
+    selected_device: Option<usize>,
+    /// Loopback/output-monitor device to capture alongside the mic and sum
+    /// into the same mono WAV; `None` disables loopback capture.
+    loopback_device: Option<usize>,
+    /// Names of the devices actually opened for the most recent recording.
+    devices_used: Arc<Mutex<DevicesUsed>>,
+    /// Which resampler the next recording's capture stream(s) build.
+    resampler_quality: ResamplerQuality,
 }
 
 impl AudioRecorder {
@@ -31,9 +145,63 @@ impl AudioRecorder {
             current_meeting_id: None,
             sample_rate: 16000,
             is_recording: Arc::new(AtomicBool::new(false)),
+            is_paused: Arc::new(AtomicBool::new(false)),
+            pause_markers: Arc::new(Mutex::new(Vec::new())),
+            samples_written: Arc::new(AtomicU64::new(0)),
+            pause_started_at: Arc::new(Mutex::new(None)),
+            paused_duration_secs: Arc::new(Mutex::new(0.0)),
+            input_gain: Arc::new(levels::gain_atomic(1.0)),
+            clip_hold_ms: 500,
+            levels: Arc::new(ChannelLevel::default()),
+            silence_threshold: 0.02,
+            auto_pause_secs: 3.0,
+            min_audio_rms: 0.003,
+            selected_device: None,
+            loopback_device: None,
+            devices_used: Arc::new(Mutex::new(DevicesUsed::default())),
+            resampler_quality: ResamplerQuality::Sinc,
         }
     }
 
+    pub fn set_device(&mut self, device_index: Option<usize>) {
+        self.selected_device = device_index;
+    }
+
+    pub fn get_selected_device(&self) -> Option<usize> {
+        self.selected_device
+    }
+
+    /// Sets the loopback/output-monitor device captured alongside the mic.
+    /// `None` disables loopback capture and records the mic alone, as before.
+    pub fn set_loopback_device(&mut self, device_index: Option<usize>) {
+        self.loopback_device = device_index;
+    }
+
+    pub fn set_vad_thresholds(&mut self, silence_threshold: f32, auto_pause_secs: f32) {
+        self.silence_threshold = silence_threshold;
+        self.auto_pause_secs = auto_pause_secs;
+    }
+
+    pub fn set_min_audio_rms(&mut self, threshold: f32) {
+        self.min_audio_rms = threshold;
+    }
+
+    /// Updates the gain multiplier applied to captured samples before
+    /// metering/writing. Takes effect immediately on the live capture thread.
+    pub fn set_input_gain(&mut self, gain: f32) {
+        levels::store_gain(&self.input_gain, gain);
+    }
+
+    pub fn set_clip_hold_ms(&mut self, clip_hold_ms: u64) {
+        self.clip_hold_ms = clip_hold_ms;
+    }
+
+    /// Selects which resampler `build_capture_stream` constructs for the
+    /// *next* recording; takes effect on the following `start_recording`.
+    pub fn set_resampler_quality(&mut self, quality: ResamplerQuality) {
+        self.resampler_quality = quality;
+    }
+
     pub fn get_state(&self) -> serde_json::Value {
         json!({
             "state": match self.state {
@@ -42,10 +210,17 @@ impl AudioRecorder {
                 RecordingState::Paused => "paused",
             },
             "meeting_id": self.current_meeting_id,
+            "pause_markers": *self.pause_markers.lock().unwrap(),
+            "paused_duration_secs": *self.paused_duration_secs.lock().unwrap(),
+            "devices_used": *self.devices_used.lock().unwrap(),
         })
     }
 
-    pub async fn start_recording(&mut self, meeting_id: &str) -> anyhow::Result<String> {
+    pub async fn start_recording(
+        &mut self,
+        meeting_id: &str,
+        app_handle: tauri::AppHandle,
+    ) -> anyhow::Result<String> {
         if self.state == RecordingState::Recording {
             return Err(anyhow::anyhow!("Already recording"));
         }
@@ -53,16 +228,54 @@ impl AudioRecorder {
         self.current_meeting_id = Some(meeting_id.to_string());
         self.state = RecordingState::Recording;
         self.is_recording.store(true, Ordering::SeqCst);
+        self.is_paused.store(false, Ordering::SeqCst);
+        *self.pause_markers.lock().unwrap() = Vec::new();
+        self.samples_written.store(0, Ordering::SeqCst);
+        *self.pause_started_at.lock().unwrap() = None;
+        *self.paused_duration_secs.lock().unwrap() = 0.0;
 
         let audio_path = self.audio_dir.join(format!("{}.wav", meeting_id));
         let target_sample_rate = self.sample_rate;
         let is_recording = Arc::clone(&self.is_recording);
+        let is_paused = Arc::clone(&self.is_paused);
+        let samples_written = Arc::clone(&self.samples_written);
+        let input_gain = Arc::clone(&self.input_gain);
+        let clip_hold_ms = self.clip_hold_ms;
+        let levels = Arc::clone(&self.levels);
+        let mic_device_index = self.selected_device;
+        let loopback_device_index = self.loopback_device;
+        let devices_used = Arc::clone(&self.devices_used);
+        let resampler_quality = self.resampler_quality;
 
         let audio_path_clone = audio_path.clone();
 
+        levels::spawn_level_emitter(
+            app_handle,
+            Arc::clone(&self.levels),
+            None,
+            Arc::clone(&self.is_recording),
+            Some(levels::VadConfig {
+                silence_threshold: self.silence_threshold,
+                auto_pause_secs: self.auto_pause_secs,
+            }),
+        );
+
         // Spawn recording in a separate thread (cpal needs to run on a real thread, not tokio)
         thread::spawn(move || {
-            if let Err(e) = record_from_microphone(audio_path_clone, target_sample_rate, is_recording) {
+            if let Err(e) = record_from_microphone(
+                audio_path_clone,
+                target_sample_rate,
+                is_recording,
+                is_paused,
+                samples_written,
+                input_gain,
+                clip_hold_ms,
+                levels,
+                mic_device_index,
+                loopback_device_index,
+                devices_used,
+                resampler_quality,
+            ) {
                 eprintln!("Audio recording error: {}", e);
             }
         });
@@ -70,7 +283,7 @@ impl AudioRecorder {
         Ok(audio_path.to_string_lossy().to_string())
     }
 
-    pub async fn stop_recording(&mut self) -> anyhow::Result<(String, u64)> {
+    pub async fn stop_recording(&mut self) -> anyhow::Result<RecordingOutcome> {
         if self.state == RecordingState::Idle {
             return Err(anyhow::anyhow!("Not recording"));
         }
@@ -81,9 +294,10 @@ impl AudioRecorder {
         tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
 
         let audio_path = self.audio_dir.join(format!("{}.wav", self.current_meeting_id.as_ref().unwrap()));
+        let audio_path_str = audio_path.to_string_lossy().to_string();
 
         // Check if file exists and has content
-        let duration = match get_audio_duration(audio_path.to_string_lossy().as_ref()) {
+        let duration = match get_audio_duration(&audio_path_str) {
             Ok(d) => d,
             Err(e) => {
                 eprintln!("Warning: Could not get audio duration: {}", e);
@@ -91,10 +305,22 @@ impl AudioRecorder {
             }
         };
 
+        // A file can be long but still pure silence (mic muted, device
+        // never actually producing input) - duration alone can't catch
+        // that, so also check the RMS across the whole recording.
+        let rms = wav_rms(&audio_path_str).unwrap_or(0.0);
+
         self.state = RecordingState::Idle;
         let meeting_id = self.current_meeting_id.take().unwrap();
 
-        Ok((meeting_id, duration as u64))
+        if duration < 0.05 || rms < self.min_audio_rms {
+            if let Err(e) = std::fs::remove_file(&audio_path) {
+                eprintln!("Warning: Could not remove empty recording {:?}: {}", audio_path, e);
+            }
+            return Ok(RecordingOutcome::NoAudioCaptured { meeting_id });
+        }
+
+        Ok(RecordingOutcome::Recorded { meeting_id, duration_secs: duration as u64 })
     }
 
     pub async fn pause_recording(&mut self) -> anyhow::Result<()> {
@@ -103,6 +329,9 @@ impl AudioRecorder {
         }
 
         self.state = RecordingState::Paused;
+        self.is_paused.store(true, Ordering::SeqCst);
+        self.pause_markers.lock().unwrap().push(self.samples_written.load(Ordering::SeqCst));
+        *self.pause_started_at.lock().unwrap() = Some(Instant::now());
         Ok(())
     }
 
@@ -112,43 +341,57 @@ impl AudioRecorder {
         }
 
         self.state = RecordingState::Recording;
+        self.is_paused.store(false, Ordering::SeqCst);
+        if let Some(started_at) = self.pause_started_at.lock().unwrap().take() {
+            *self.paused_duration_secs.lock().unwrap() += started_at.elapsed().as_secs_f64();
+        }
         Ok(())
     }
 }
 
-fn record_from_microphone(
-    output_path: PathBuf,
+/// Opens `device` and builds an input stream that metering-samples,
+/// gain-applies, resamples to `target_sample_rate` and pushes the result into
+/// `queue`. Shared by the mic and loopback streams in `record_from_microphone`
+/// so supporting a second simultaneous device didn't mean duplicating the
+/// F32/I16 dispatch twice over. `levels` is `None` for the loopback stream -
+/// metering is mic-focused and a loopback source shouldn't drive the VU meter
+/// or auto-pause.
+fn build_capture_stream(
+    device: &cpal::Device,
     target_sample_rate: u32,
     is_recording: Arc<AtomicBool>,
-) -> anyhow::Result<()> {
-    let host = cpal::default_host();
-    let device = host.default_input_device()
-        .ok_or_else(|| anyhow::anyhow!("No input device available"))?;
-
+    is_paused: Arc<AtomicBool>,
+    input_gain: Arc<AtomicU32>,
+    clip_hold_ms: u64,
+    levels: Option<Arc<ChannelLevel>>,
+    queue: Arc<Mutex<VecDeque<f32>>>,
+    resampler_quality: ResamplerQuality,
+) -> anyhow::Result<(cpal::Stream, Arc<std::sync::Mutex<LiveResampler>>)> {
     let config = device.default_input_config()?;
     let input_sample_rate = config.sample_rate().0;
     let channels = config.channels();
 
-    println!("Recording from: {:?}", device.name()?);
-    println!("Input sample rate: {}, Channels: {}, Target: {}", input_sample_rate, channels, target_sample_rate);
-
-    // Create WAV file with target sample rate
-    let spec = WavSpec {
-        channels: 1,
-        sample_rate: target_sample_rate,
-        bits_per_sample: 16,
-        sample_format: hound::SampleFormat::Int,
-    };
-
-    let file = File::create(&output_path)?;
-    let buf_writer = BufWriter::new(file);
-    let wav_writer = Arc::new(std::sync::Mutex::new(Some(WavWriter::new(buf_writer, spec)?)));
-
-    let wav_writer_clone = Arc::clone(&wav_writer);
-    let is_recording_clone = Arc::clone(&is_recording);
-
-    // Buffer for resampling
-    let resample_ratio = target_sample_rate as f64 / input_sample_rate as f64;
+    // Resampler, shared so the tail can be flushed once the stream stops
+    // (the capture callback only ever touches it from one thread at a time,
+    // but the flush happens after the stream is dropped).
+    let resampler = Arc::new(std::sync::Mutex::new(LiveResampler::new(
+        resampler_quality,
+        input_sample_rate,
+        target_sample_rate,
+    )?));
+    let resampler_f32 = Arc::clone(&resampler);
+    let resampler_i16 = Arc::clone(&resampler);
+
+    let is_recording_f32 = Arc::clone(&is_recording);
+    let is_recording_i16 = Arc::clone(&is_recording);
+    let is_paused_f32 = Arc::clone(&is_paused);
+    let is_paused_i16 = Arc::clone(&is_paused);
+    let input_gain_f32 = Arc::clone(&input_gain);
+    let input_gain_i16 = Arc::clone(&input_gain);
+    let levels_f32 = levels.clone();
+    let levels_i16 = levels;
+    let queue_f32 = Arc::clone(&queue);
+    let queue_i16 = Arc::clone(&queue);
 
     let err_fn = |err| eprintln!("Audio stream error: {}", err);
 
@@ -157,7 +400,7 @@ fn record_from_microphone(
             device.build_input_stream(
                 &config.into(),
                 move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                    if !is_recording_clone.load(Ordering::SeqCst) {
+                    if !is_recording_f32.load(Ordering::SeqCst) {
                         return;
                     }
 
@@ -168,33 +411,30 @@ fn record_from_microphone(
                         data.to_vec()
                     };
 
-                    // Simple resampling (linear interpolation)
-                    let resampled = if (resample_ratio - 1.0).abs() > 0.01 {
-                        let output_len = (mono.len() as f64 * resample_ratio) as usize;
-                        let mut output = Vec::with_capacity(output_len);
-                        for i in 0..output_len {
-                            let src_idx = i as f64 / resample_ratio;
-                            let idx0 = src_idx.floor() as usize;
-                            let idx1 = (idx0 + 1).min(mono.len().saturating_sub(1));
-                            let frac = src_idx - idx0 as f64;
-                            let sample = mono.get(idx0).copied().unwrap_or(0.0) * (1.0 - frac as f32)
-                                + mono.get(idx1).copied().unwrap_or(0.0) * frac as f32;
-                            output.push(sample);
-                        }
-                        output
-                    } else {
-                        mono
+                    let gain = levels::load_gain(&input_gain_f32);
+                    if let Some(levels) = &levels_f32 {
+                        let (rms, peak) = levels::rms_and_peak(&mono, gain);
+                        levels.update(rms, peak, clip_hold_ms);
+                    }
+
+                    // Metering still runs while paused (so the user can see
+                    // their mic level), but the frame itself is dropped
+                    // before resampling/queuing so the WAV file comes out
+                    // gapless instead of holding silence for the pause.
+                    if is_paused_f32.load(Ordering::SeqCst) {
+                        return;
+                    }
+
+                    let mono: Vec<f32> = mono.into_iter().map(|s| s * gain).collect();
+
+                    // Resample to the target rate
+                    let resampled = match resampler_f32.lock() {
+                        Ok(mut r) => r.process(&mono),
+                        Err(_) => Vec::new(),
                     };
 
-                    // Write to WAV
-                    if let Ok(mut guard) = wav_writer_clone.lock() {
-                        if let Some(ref mut writer) = *guard {
-                            for sample in resampled {
-                                let clamped = sample.max(-1.0).min(1.0);
-                                let int_sample = (clamped * 32767.0) as i16;
-                                let _ = writer.write_sample(int_sample);
-                            }
-                        }
+                    if let Ok(mut q) = queue_f32.lock() {
+                        q.extend(resampled);
                     }
                 },
                 err_fn,
@@ -205,7 +445,7 @@ fn record_from_microphone(
             device.build_input_stream(
                 &config.into(),
                 move |data: &[i16], _: &cpal::InputCallbackInfo| {
-                    if !is_recording_clone.load(Ordering::SeqCst) {
+                    if !is_recording_i16.load(Ordering::SeqCst) {
                         return;
                     }
 
@@ -218,33 +458,29 @@ fn record_from_microphone(
                         data.iter().map(|&s| s as f32 / 32768.0).collect()
                     };
 
-                    // Simple resampling
-                    let resampled = if (resample_ratio - 1.0).abs() > 0.01 {
-                        let output_len = (mono.len() as f64 * resample_ratio) as usize;
-                        let mut output = Vec::with_capacity(output_len);
-                        for i in 0..output_len {
-                            let src_idx = i as f64 / resample_ratio;
-                            let idx0 = src_idx.floor() as usize;
-                            let idx1 = (idx0 + 1).min(mono.len().saturating_sub(1));
-                            let frac = src_idx - idx0 as f64;
-                            let sample = mono.get(idx0).copied().unwrap_or(0.0) * (1.0 - frac as f32)
-                                + mono.get(idx1).copied().unwrap_or(0.0) * frac as f32;
-                            output.push(sample);
-                        }
-                        output
-                    } else {
-                        mono
+                    let gain = levels::load_gain(&input_gain_i16);
+                    if let Some(levels) = &levels_i16 {
+                        let (rms, peak) = levels::rms_and_peak(&mono, gain);
+                        levels.update(rms, peak, clip_hold_ms);
+                    }
+
+                    // See the F32 callback above: metering still runs while
+                    // paused, but the frame is dropped before
+                    // resampling/queuing so the file stays gapless.
+                    if is_paused_i16.load(Ordering::SeqCst) {
+                        return;
+                    }
+
+                    let mono: Vec<f32> = mono.into_iter().map(|s| s * gain).collect();
+
+                    // Resample to the target rate
+                    let resampled = match resampler_i16.lock() {
+                        Ok(mut r) => r.process(&mono),
+                        Err(_) => Vec::new(),
                     };
 
-                    // Write to WAV
-                    if let Ok(mut guard) = wav_writer_clone.lock() {
-                        if let Some(ref mut writer) = *guard {
-                            for sample in resampled {
-                                let clamped = sample.max(-1.0).min(1.0);
-                                let int_sample = (clamped * 32767.0) as i16;
-                                let _ = writer.write_sample(int_sample);
-                            }
-                        }
+                    if let Ok(mut q) = queue_i16.lock() {
+                        q.extend(resampled);
                     }
                 },
                 err_fn,
@@ -254,24 +490,170 @@ fn record_from_microphone(
         format => return Err(anyhow::anyhow!("Unsupported sample format: {:?}", format)),
     };
 
-    stream.play()?;
+    Ok((stream, resampler))
+}
+
+/// Drains whatever's queued from the mic (and, if capturing one, the
+/// loopback device), sum-and-clamps them sample-for-sample, and returns the
+/// mixed mono frame ready to write. The two queues fill at roughly the same
+/// rate since both resample to the same target rate, so draining them
+/// together on the same poll tick keeps them from drifting apart; a missing
+/// sample on either side is treated as silence for that tick.
+fn drain_and_mix(
+    mic_queue: &Arc<Mutex<VecDeque<f32>>>,
+    loopback_queue: Option<&Arc<Mutex<VecDeque<f32>>>>,
+) -> Vec<f32> {
+    let mic_chunk: Vec<f32> = mic_queue.lock().map(|mut q| q.drain(..).collect()).unwrap_or_default();
+
+    match loopback_queue {
+        None => mic_chunk,
+        Some(lq) => {
+            let loop_chunk: Vec<f32> = lq.lock().map(|mut q| q.drain(..).collect()).unwrap_or_default();
+            let len = mic_chunk.len().max(loop_chunk.len());
+            (0..len)
+                .map(|i| {
+                    let m = mic_chunk.get(i).copied().unwrap_or(0.0);
+                    let l = loop_chunk.get(i).copied().unwrap_or(0.0);
+                    (m + l).clamp(-1.0, 1.0)
+                })
+                .collect()
+        }
+    }
+}
+
+fn write_mixed_samples(writer: &mut WavWriter<BufWriter<File>>, samples: &[f32], samples_written: &Arc<AtomicU64>) {
+    for &sample in samples {
+        let clamped = sample.max(-1.0).min(1.0);
+        let int_sample = (clamped * 32767.0) as i16;
+        let _ = writer.write_sample(int_sample);
+    }
+    samples_written.fetch_add(samples.len() as u64, Ordering::SeqCst);
+}
+
+fn record_from_microphone(
+    output_path: PathBuf,
+    target_sample_rate: u32,
+    is_recording: Arc<AtomicBool>,
+    is_paused: Arc<AtomicBool>,
+    samples_written: Arc<AtomicU64>,
+    input_gain: Arc<AtomicU32>,
+    clip_hold_ms: u64,
+    levels: Arc<ChannelLevel>,
+    mic_device_index: Option<usize>,
+    loopback_device_index: Option<usize>,
+    devices_used: Arc<Mutex<DevicesUsed>>,
+    resampler_quality: ResamplerQuality,
+) -> anyhow::Result<()> {
+    let mic_device = match mic_device_index {
+        Some(idx) => get_device_by_index(idx)?,
+        None => cpal::default_host()
+            .default_input_device()
+            .ok_or_else(|| anyhow::anyhow!("No input device available"))?,
+    };
+
+    let mic_name = mic_device.name().unwrap_or_else(|_| "Unknown device".to_string());
+    println!("Recording from: {}", mic_name);
+
+    // Create WAV file with target sample rate
+    let spec = WavSpec {
+        channels: 1,
+        sample_rate: target_sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let file = File::create(&output_path)?;
+    let buf_writer = BufWriter::new(file);
+    let mut wav_writer = WavWriter::new(buf_writer, spec)?;
+
+    let mic_queue: Arc<Mutex<VecDeque<f32>>> = Arc::new(Mutex::new(VecDeque::new()));
+    let (mic_stream, mic_resampler) = build_capture_stream(
+        &mic_device,
+        target_sample_rate,
+        Arc::clone(&is_recording),
+        Arc::clone(&is_paused),
+        Arc::clone(&input_gain),
+        clip_hold_ms,
+        Some(Arc::clone(&levels)),
+        Arc::clone(&mic_queue),
+        resampler_quality,
+    )?;
+
+    // Loopback/output-monitor device captured alongside the mic and summed
+    // into the same mono WAV, so recorded meetings include both local speech
+    // and remote participants played back through the system.
+    let loopback = match loopback_device_index {
+        Some(idx) => {
+            let loopback_device = get_device_by_index(idx)?;
+            let loopback_name = loopback_device.name().unwrap_or_else(|_| "Unknown device".to_string());
+            println!("Capturing loopback from: {}", loopback_name);
+
+            let loopback_queue: Arc<Mutex<VecDeque<f32>>> = Arc::new(Mutex::new(VecDeque::new()));
+            let (stream, resampler) = build_capture_stream(
+                &loopback_device,
+                target_sample_rate,
+                Arc::clone(&is_recording),
+                Arc::clone(&is_paused),
+                Arc::clone(&input_gain),
+                clip_hold_ms,
+                None,
+                Arc::clone(&loopback_queue),
+                resampler_quality,
+            )?;
+
+            *devices_used.lock().unwrap() = DevicesUsed {
+                mic_device: Some(mic_name.clone()),
+                loopback_device: Some(loopback_name),
+            };
+
+            Some((stream, resampler, loopback_queue))
+        }
+        None => {
+            *devices_used.lock().unwrap() = DevicesUsed { mic_device: Some(mic_name.clone()), loopback_device: None };
+            None
+        }
+    };
+
+    // Split the loopback stream apart from its resampler/queue up front so
+    // the stream (and its capture thread) can be dropped independently of
+    // the state needed to flush and mix the final frame below.
+    let (loopback_stream, loopback_resampler, loopback_queue) = match loopback {
+        Some((stream, resampler, queue)) => (Some(stream), Some(resampler), Some(queue)),
+        None => (None, None, None),
+    };
+
+    mic_stream.play()?;
+    if let Some(stream) = &loopback_stream {
+        stream.play()?;
+    }
     println!("Recording started...");
 
-    // Keep the stream alive while recording
+    // Keep the streams alive while recording, draining and mixing their
+    // queues onto the WAV file each tick so a simultaneous loopback device
+    // never has to be written from inside a cpal callback.
     while is_recording.load(Ordering::SeqCst) {
         thread::sleep(std::time::Duration::from_millis(100));
+        let mixed = drain_and_mix(&mic_queue, loopback_queue.as_ref());
+        write_mixed_samples(&mut wav_writer, &mixed, &samples_written);
     }
 
-    // Stop and finalize
-    drop(stream);
+    // Stop capture, then drain each resampler's group delay so the tail of
+    // the recording isn't lost, mix, and write the final frame.
+    drop(mic_stream);
+    drop(loopback_stream);
 
-    // Finalize the WAV file
-    if let Ok(mut guard) = wav_writer.lock() {
-        if let Some(writer) = guard.take() {
-            writer.finalize()?;
-            println!("Recording saved to: {:?}", output_path);
-        }
+    let mic_tail = mic_resampler.lock().map(|mut r| r.flush()).unwrap_or_default();
+    mic_queue.lock().unwrap().extend(mic_tail);
+
+    if let Some(loopback_resampler) = &loopback_resampler {
+        let loop_tail = loopback_resampler.lock().map(|mut r| r.flush()).unwrap_or_default();
+        loopback_queue.as_ref().unwrap().lock().unwrap().extend(loop_tail);
     }
+    let tail_mixed = drain_and_mix(&mic_queue, loopback_queue.as_ref());
+    write_mixed_samples(&mut wav_writer, &tail_mixed, &samples_written);
+
+    wav_writer.finalize()?;
+    println!("Recording saved to: {:?}", output_path);
 
     Ok(())
 }
@@ -285,6 +667,27 @@ pub fn get_audio_duration(audio_path: &str) -> anyhow::Result<f64> {
     Ok(duration_seconds)
 }
 
+/// RMS amplitude across every sample in the 16-bit mono WAV at `audio_path`,
+/// used by `AudioRecorder::stop_recording` to tell a genuinely silent
+/// recording (mic muted, no input device data) apart from a real one.
+fn wav_rms(audio_path: &str) -> anyhow::Result<f32> {
+    let mut reader = hound::WavReader::open(audio_path)?;
+    let mut sum_sq = 0.0f64;
+    let mut count = 0u64;
+
+    for sample in reader.samples::<i16>() {
+        let s = sample? as f64 / 32768.0;
+        sum_sq += s * s;
+        count += 1;
+    }
+
+    if count == 0 {
+        return Ok(0.0);
+    }
+
+    Ok((sum_sq / count as f64).sqrt() as f32)
+}
+
 pub fn resample_audio(input: &[f32], input_rate: u32, output_rate: u32) -> anyhow::Result<Vec<f32>> {
     use rubato::{SincInterpolationParameters, SincInterpolationType, Resampler, SincFixedIn};
 
@@ -313,3 +716,203 @@ pub fn resample_audio(input: &[f32], input_rate: u32, output_rate: u32) -> anyho
 
     Ok(waves_out.into_iter().next().unwrap_or_default())
 }
+
+/// Fixed-size input block a `SincResampler` accumulates before it runs the
+/// filter. cpal hands capture callbacks variable-size buffers, so samples
+/// are carried across calls until a full block is available.
+const SINC_RESAMPLER_CHUNK_SIZE: usize = 1024;
+
+/// Band-limited windowed-sinc resampler for the live capture path, replacing
+/// the naive linear-interpolation resampling that used to alias badly and
+/// hurt downstream ASR accuracy. Wraps `rubato`'s `SincFixedIn` (the same
+/// filter design already used for one-shot resampling in `resample_audio`)
+/// behind a carry buffer so it can be fed cpal's variable-size callbacks one
+/// mono chunk at a time instead of requiring a fixed block size up front.
+pub struct SincResampler {
+    resampler: rubato::SincFixedIn<f32>,
+    carry: Vec<f32>,
+    passthrough: bool,
+}
+
+impl SincResampler {
+    pub fn new(input_rate: u32, output_rate: u32) -> anyhow::Result<Self> {
+        use rubato::{SincInterpolationParameters, SincInterpolationType, SincFixedIn, WindowFunction};
+
+        let ratio = output_rate as f64 / input_rate as f64;
+        let passthrough = (ratio - 1.0).abs() < 0.01;
+
+        let params = SincInterpolationParameters {
+            sinc_len: 256,
+            f_cutoff: 0.95,
+            interpolation: SincInterpolationType::Linear,
+            oversampling_factor: 256,
+            window: WindowFunction::BlackmanHarris2,
+        };
+
+        let resampler = SincFixedIn::<f32>::new(ratio, 2.0, params, SINC_RESAMPLER_CHUNK_SIZE, 1)?;
+
+        Ok(Self { resampler, carry: Vec::new(), passthrough })
+    }
+
+    /// Appends `samples` to the carry buffer and runs the filter over every
+    /// full chunk now available, returning the resampled output produced so
+    /// far. Leftover samples that don't fill a whole chunk stay in the carry
+    /// buffer for the next call.
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        use rubato::Resampler;
+
+        if self.passthrough {
+            return samples.to_vec();
+        }
+
+        self.carry.extend_from_slice(samples);
+
+        let mut output = Vec::new();
+        while self.carry.len() >= SINC_RESAMPLER_CHUNK_SIZE {
+            let block: Vec<f32> = self.carry.drain(..SINC_RESAMPLER_CHUNK_SIZE).collect();
+            if let Ok(mut waves_out) = self.resampler.process(&[block], None) {
+                if let Some(chunk) = waves_out.pop() {
+                    output.extend(chunk);
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Pads whatever is left in the carry buffer with zeros up to two full
+    /// chunks and runs both through the filter, so at least a whole chunk of
+    /// zeros follows the last real sample - more than the filter's delay
+    /// (half its 256-tap length) - draining the group delay so the tail of
+    /// the recording isn't lost. Call once, when the stream stops.
+    pub fn flush(&mut self) -> Vec<f32> {
+        if self.passthrough {
+            return Vec::new();
+        }
+
+        // `process` never leaves a full chunk behind, so this only grows it.
+        self.carry.resize(2 * SINC_RESAMPLER_CHUNK_SIZE, 0.0);
+        self.process(&[])
+    }
+}
+
+/// Fixed-size input block an `FftResampler` accumulates before it runs the
+/// filter, same role as `SINC_RESAMPLER_CHUNK_SIZE`.
+const FFT_RESAMPLER_CHUNK_SIZE: usize = 1024;
+
+/// Lower-CPU alternative to `SincResampler` for the live capture path.
+/// Wraps rubato's `FftFixedInOut`, which resamples a whole block via FFT
+/// rather than evaluating a windowed-sinc kernel per output sample - cheaper
+/// for rate ratios that reduce to a small fraction (e.g. 48kHz -> 16kHz is
+/// 3:1) at a small quality cost versus `SincResampler`, which matters more
+/// on a live capture thread than in the one-shot `resample_audio` path.
+/// Same carry-buffer/flush shape as `SincResampler`, so it's a drop-in
+/// alternative wherever that CPU/quality tradeoff is worth it.
+pub struct FftResampler {
+    resampler: rubato::FftFixedInOut<f32>,
+    carry: Vec<f32>,
+    passthrough: bool,
+}
+
+impl FftResampler {
+    pub fn new(input_rate: u32, output_rate: u32) -> anyhow::Result<Self> {
+        use rubato::FftFixedInOut;
+
+        let passthrough = input_rate == output_rate;
+        let resampler = FftFixedInOut::<f32>::new(
+            input_rate as usize,
+            output_rate as usize,
+            FFT_RESAMPLER_CHUNK_SIZE,
+            1,
+        )?;
+
+        Ok(Self { resampler, carry: Vec::new(), passthrough })
+    }
+
+    /// Appends `samples` to the carry buffer and runs the filter over every
+    /// full chunk now available, returning the resampled output produced so
+    /// far. Leftover samples that don't fill a whole chunk stay in the carry
+    /// buffer for the next call.
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        use rubato::Resampler;
+
+        if self.passthrough {
+            return samples.to_vec();
+        }
+
+        self.carry.extend_from_slice(samples);
+
+        let chunk_size = self.resampler.input_frames_next();
+        let mut output = Vec::new();
+        while self.carry.len() >= chunk_size {
+            let block: Vec<f32> = self.carry.drain(..chunk_size).collect();
+            if let Ok(mut waves_out) = self.resampler.process(&[block], None) {
+                if let Some(chunk) = waves_out.pop() {
+                    output.extend(chunk);
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Pads whatever is left in the carry buffer with zeros up to two full
+    /// chunks and runs both through the filter, so at least a whole chunk of
+    /// zeros follows the last real sample, draining the group delay so the
+    /// tail of the recording isn't lost. Call once, when the stream stops.
+    pub fn flush(&mut self) -> Vec<f32> {
+        use rubato::Resampler;
+
+        if self.passthrough {
+            return Vec::new();
+        }
+
+        // `process` never leaves a full chunk behind, so this only grows it.
+        let chunk_size = self.resampler.input_frames_next();
+        self.carry.resize(2 * chunk_size, 0.0);
+        self.process(&[])
+    }
+}
+
+/// Which resampler implementation the live capture path builds. `Sinc` is
+/// the default, highest-quality choice; `Fft` trades a little quality for
+/// meaningfully less CPU (see `FftResampler`'s doc comment), worth offering
+/// on machines where live capture competes with whisper for CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResamplerQuality {
+    Sinc,
+    Fft,
+}
+
+/// Dispatches to whichever resampler `ResamplerQuality` selected. Both
+/// variants share the same carry-buffer `process`/`flush` shape, so callers
+/// don't need to know which one is live underneath.
+pub enum LiveResampler {
+    Sinc(SincResampler),
+    Fft(FftResampler),
+}
+
+impl LiveResampler {
+    pub fn new(quality: ResamplerQuality, input_rate: u32, output_rate: u32) -> anyhow::Result<Self> {
+        Ok(match quality {
+            ResamplerQuality::Sinc => Self::Sinc(SincResampler::new(input_rate, output_rate)?),
+            ResamplerQuality::Fft => Self::Fft(FftResampler::new(input_rate, output_rate)?),
+        })
+    }
+
+    pub fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        match self {
+            Self::Sinc(r) => r.process(samples),
+            Self::Fft(r) => r.process(samples),
+        }
+    }
+
+    pub fn flush(&mut self) -> Vec<f32> {
+        match self {
+            Self::Sinc(r) => r.flush(),
+            Self::Fft(r) => r.flush(),
+        }
+    }
+}
+
@@ -2,16 +2,108 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tokio::fs;
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+use crate::audio::ResamplerQuality;
+use crate::credentials;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
-    pub ai_api_key: Option<String>,
     pub ai_provider: Option<String>,
+    /// Reference into the system keyring (see `credentials`) for the
+    /// provider's API key - never the key itself. `None` means no key has
+    /// been saved, or it was saved before this field existed.
+    pub ai_key_id: Option<String>,
     /// DEPRECATED: Use mic_device_index instead
     pub selected_audio_device: Option<usize>,
     /// Dual audio: microphone device index
     pub mic_device_index: Option<usize>,
     /// Dual audio: system audio monitor device index
     pub system_device_index: Option<usize>,
+    /// Dual audio: additional capture sources beyond mic/system (e.g. a
+    /// second mic, or other participants' loopback devices), each written
+    /// to its own side file alongside the mic/system stereo recording -
+    /// see `dual_audio::record_multi_streams`.
+    #[serde(default = "default_extra_device_indices")]
+    pub extra_device_indices: Vec<usize>,
+    /// Single-mic recording: loopback/output-monitor device captured
+    /// alongside the mic and summed into the same mono WAV
+    pub loopback_device_index: Option<usize>,
+    /// Multiplier applied to captured samples before metering/writing
+    #[serde(default = "default_input_gain")]
+    pub input_gain: f32,
+    /// How long the clipping indicator stays lit after a peak is detected
+    #[serde(default = "default_clip_hold_ms")]
+    pub clip_hold_ms: u64,
+    /// Linear-amplitude RMS floor below which audio is considered silence
+    #[serde(default = "default_silence_threshold")]
+    pub silence_threshold: f32,
+    /// How many continuous seconds of silence trigger an auto-pause
+    #[serde(default = "default_auto_pause_secs")]
+    pub auto_pause_secs: f32,
+    /// Whether to drop long silent spans from a recording before transcribing it
+    #[serde(default = "default_trim_silence")]
+    pub trim_silence: bool,
+    /// Max number of Whisper transcriptions allowed to run at once (e.g. the
+    /// mic/system channels of a dual recording, or a future batch job)
+    #[serde(default = "default_max_parallel_transcriptions")]
+    pub max_parallel_transcriptions: usize,
+    /// Resampler the live capture path builds for mic/system/loopback
+    /// streams; `Fft` trades a little quality for less CPU (see
+    /// `audio::FftResampler`).
+    #[serde(default = "default_resampler_quality")]
+    pub resampler_quality: ResamplerQuality,
+}
+
+fn default_input_gain() -> f32 {
+    1.0
+}
+
+fn default_clip_hold_ms() -> u64 {
+    500
+}
+
+fn default_silence_threshold() -> f32 {
+    0.02
+}
+
+fn default_auto_pause_secs() -> f32 {
+    3.0
+}
+
+fn default_trim_silence() -> bool {
+    true
+}
+
+fn default_max_parallel_transcriptions() -> usize {
+    2
+}
+
+fn default_resampler_quality() -> ResamplerQuality {
+    ResamplerQuality::Sinc
+}
+
+fn default_extra_device_indices() -> Vec<usize> {
+    Vec::new()
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            ai_provider: None,
+            ai_key_id: None,
+            selected_audio_device: None,
+            mic_device_index: None,
+            system_device_index: None,
+            extra_device_indices: default_extra_device_indices(),
+            loopback_device_index: None,
+            input_gain: default_input_gain(),
+            clip_hold_ms: default_clip_hold_ms(),
+            silence_threshold: default_silence_threshold(),
+            auto_pause_secs: default_auto_pause_secs(),
+            trim_silence: default_trim_silence(),
+            max_parallel_transcriptions: default_max_parallel_transcriptions(),
+            resampler_quality: default_resampler_quality(),
+        }
+    }
 }
 
 impl AppConfig {
@@ -31,6 +123,14 @@ impl AppConfig {
 
         match fs::read_to_string(&path).await {
             Ok(content) => {
+                // `ai_api_key` no longer exists on `AppConfig` - peek at the
+                // raw JSON for it before the typed parse drops it silently,
+                // so a legacy plaintext key can still be migrated.
+                let legacy_api_key = serde_json::from_str::<serde_json::Value>(&content)
+                    .ok()
+                    .and_then(|v| v.get("ai_api_key").and_then(|k| k.as_str()).map(String::from))
+                    .filter(|k| !k.is_empty());
+
                 let mut config: Self = serde_json::from_str(&content).unwrap_or_default();
                 // Migrate legacy selected_audio_device to mic_device_index
                 if config.selected_audio_device.is_some() && config.mic_device_index.is_none() {
@@ -40,6 +140,22 @@ impl AppConfig {
                         eprintln!("Failed to save migrated config: {}", e);
                     }
                 }
+
+                if let Some(plaintext_key) = legacy_api_key {
+                    let provider = config.ai_provider.clone().unwrap_or_else(|| "openai".to_string());
+                    match credentials::store_api_key(&provider, credentials::DEFAULT_KEY_ID, &plaintext_key) {
+                        Ok(()) => {
+                            config.ai_key_id = Some(credentials::DEFAULT_KEY_ID.to_string());
+                            if let Err(e) = config.save().await {
+                                eprintln!("Failed to save config after keyring migration: {}", e);
+                            } else {
+                                println!("Migrated API key from config.json into the system keyring");
+                            }
+                        }
+                        Err(e) => eprintln!("Failed to migrate API key into the system keyring: {}", e),
+                    }
+                }
+
                 config
             }
             Err(_) => Self::default(),
@@ -61,7 +177,8 @@ impl AppConfig {
     }
 
     pub async fn set_ai_credentials(&mut self, api_key: &str, provider: &str) -> anyhow::Result<()> {
-        self.ai_api_key = Some(api_key.to_string());
+        credentials::store_api_key(provider, credentials::DEFAULT_KEY_ID, api_key)?;
+        self.ai_key_id = Some(credentials::DEFAULT_KEY_ID.to_string());
         self.ai_provider = Some(provider.to_string());
         self.save().await
     }
@@ -0,0 +1,354 @@
+use std::path::PathBuf;
+use serde::Serialize;
+use tokio::sync::{broadcast, mpsc, oneshot};
+
+use crate::audio::{AudioRecorder, RecordingOutcome, ResamplerQuality};
+use crate::dual_audio::{DualAudioRecorder, DualRecordingResult, DualRecordingStatus};
+
+/// Commands the audio-control task understands. Every `#[tauri::command]`
+/// that used to lock `audio`/`dual_audio` directly now sends one of these and
+/// awaits the oneshot reply instead.
+pub enum AudioControlMessage {
+    StartRecording {
+        meeting_id: String,
+        app_handle: tauri::AppHandle,
+        reply: oneshot::Sender<anyhow::Result<String>>,
+    },
+    StopRecording {
+        reply: oneshot::Sender<anyhow::Result<RecordingOutcome>>,
+    },
+    PauseRecording {
+        reply: oneshot::Sender<anyhow::Result<()>>,
+    },
+    ResumeRecording {
+        reply: oneshot::Sender<anyhow::Result<()>>,
+    },
+    SetDevice {
+        device_index: Option<usize>,
+        reply: oneshot::Sender<()>,
+    },
+    GetSelectedDevice {
+        reply: oneshot::Sender<Option<usize>>,
+    },
+    SetLoopbackDevice {
+        device_index: Option<usize>,
+        reply: oneshot::Sender<()>,
+    },
+    StartDual {
+        meeting_id: String,
+        mic_device_index: Option<usize>,
+        system_device_index: Option<usize>,
+        extra_device_indices: Vec<usize>,
+        app_handle: tauri::AppHandle,
+        reply: oneshot::Sender<anyhow::Result<DualRecordingStatus>>,
+    },
+    StopDual {
+        reply: oneshot::Sender<anyhow::Result<DualRecordingResult>>,
+    },
+    SetInputGain {
+        gain: f32,
+        reply: oneshot::Sender<()>,
+    },
+    SetVadThresholds {
+        silence_threshold: f32,
+        auto_pause_secs: f32,
+        reply: oneshot::Sender<()>,
+    },
+    SetClipHoldMs {
+        clip_hold_ms: u64,
+        reply: oneshot::Sender<()>,
+    },
+    SetResamplerQuality {
+        quality: ResamplerQuality,
+        reply: oneshot::Sender<()>,
+    },
+    MuteMic {
+        muted: bool,
+        reply: oneshot::Sender<()>,
+    },
+    MuteSystem {
+        muted: bool,
+        reply: oneshot::Sender<()>,
+    },
+    GetStatus {
+        reply: oneshot::Sender<AudioStatusMessage>,
+    },
+}
+
+/// Single source of truth for "which mode is recording", broadcast after
+/// every start/stop/pause/resume/mute command the audio-control task handles,
+/// so the frontend doesn't have to poll `get_recording_state` for those.
+/// Changes inside a running recording that no command caused (a device
+/// disconnect, an auto-pause, a capture error) are not broadcast here.
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioStatusMessage {
+    pub state: String, // "idle" | "recording" | "paused"
+    pub is_dual_mode: bool,
+    pub meeting_id: Option<String>,
+    pub mic_active: bool,
+    pub system_active: bool,
+    pub mic_device: Option<String>,
+    pub system_device: Option<String>,
+    pub mic_muted: bool,
+    pub system_muted: bool,
+    /// Sample offsets (from `AudioRecorder::get_state`) where a pause began;
+    /// always empty in dual mode, which doesn't track pause markers.
+    pub pause_markers: Vec<u64>,
+    /// Always 0.0 in dual mode, which doesn't track paused time.
+    pub paused_duration_secs: f64,
+}
+
+/// Thin, cloneable front for the audio-control task. Commands talk to this
+/// instead of locking `AudioRecorder`/`DualAudioRecorder` mutexes directly.
+#[derive(Clone)]
+pub struct AudioActorHandle {
+    tx: mpsc::Sender<AudioControlMessage>,
+}
+
+impl AudioActorHandle {
+    async fn call<R>(
+        &self,
+        make_msg: impl FnOnce(oneshot::Sender<R>) -> AudioControlMessage,
+    ) -> anyhow::Result<R> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.tx
+            .send(make_msg(reply_tx))
+            .await
+            .map_err(|_| anyhow::anyhow!("Audio control task is not running"))?;
+        reply_rx
+            .await
+            .map_err(|_| anyhow::anyhow!("Audio control task dropped the reply"))
+    }
+
+    pub async fn start_recording(&self, meeting_id: String, app_handle: tauri::AppHandle) -> anyhow::Result<String> {
+        self.call(|reply| AudioControlMessage::StartRecording { meeting_id, app_handle, reply }).await?
+    }
+
+    pub async fn stop_recording(&self) -> anyhow::Result<RecordingOutcome> {
+        self.call(|reply| AudioControlMessage::StopRecording { reply }).await?
+    }
+
+    pub async fn pause_recording(&self) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::PauseRecording { reply }).await?
+    }
+
+    pub async fn resume_recording(&self) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::ResumeRecording { reply }).await?
+    }
+
+    pub async fn set_device(&self, device_index: Option<usize>) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::SetDevice { device_index, reply }).await
+    }
+
+    pub async fn get_selected_device(&self) -> anyhow::Result<Option<usize>> {
+        self.call(|reply| AudioControlMessage::GetSelectedDevice { reply }).await
+    }
+
+    pub async fn set_loopback_device(&self, device_index: Option<usize>) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::SetLoopbackDevice { device_index, reply }).await
+    }
+
+    pub async fn start_dual(
+        &self,
+        meeting_id: String,
+        mic_device_index: Option<usize>,
+        system_device_index: Option<usize>,
+        extra_device_indices: Vec<usize>,
+        app_handle: tauri::AppHandle,
+    ) -> anyhow::Result<DualRecordingStatus> {
+        self.call(|reply| AudioControlMessage::StartDual {
+            meeting_id,
+            mic_device_index,
+            system_device_index,
+            extra_device_indices,
+            app_handle,
+            reply,
+        })
+        .await?
+    }
+
+    pub async fn stop_dual(&self) -> anyhow::Result<DualRecordingResult> {
+        self.call(|reply| AudioControlMessage::StopDual { reply }).await?
+    }
+
+    pub async fn set_input_gain(&self, gain: f32) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::SetInputGain { gain, reply }).await
+    }
+
+    pub async fn set_vad_thresholds(&self, silence_threshold: f32, auto_pause_secs: f32) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::SetVadThresholds { silence_threshold, auto_pause_secs, reply }).await
+    }
+
+    pub async fn set_clip_hold_ms(&self, clip_hold_ms: u64) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::SetClipHoldMs { clip_hold_ms, reply }).await
+    }
+
+    pub async fn set_resampler_quality(&self, quality: ResamplerQuality) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::SetResamplerQuality { quality, reply }).await
+    }
+
+    pub async fn mute_mic(&self, muted: bool) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::MuteMic { muted, reply }).await
+    }
+
+    pub async fn mute_system(&self, muted: bool) -> anyhow::Result<()> {
+        self.call(|reply| AudioControlMessage::MuteSystem { muted, reply }).await
+    }
+
+    pub async fn get_status(&self) -> anyhow::Result<AudioStatusMessage> {
+        self.call(|reply| AudioControlMessage::GetStatus { reply }).await
+    }
+}
+
+/// Spawns the audio-control task that owns both recorders and returns a
+/// handle for commands to talk to it, plus a broadcast sender so a forwarder
+/// can turn status transitions into Tauri events.
+pub fn spawn(audio_dir: PathBuf) -> (AudioActorHandle, broadcast::Sender<AudioStatusMessage>) {
+    let (tx, mut rx) = mpsc::channel::<AudioControlMessage>(32);
+    let (status_tx, _) = broadcast::channel::<AudioStatusMessage>(32);
+    let status_tx_task = status_tx.clone();
+
+    tauri::async_runtime::spawn(async move {
+        let mut audio = AudioRecorder::new(audio_dir.clone());
+        let mut dual_audio = DualAudioRecorder::new(audio_dir);
+        let mut dual_mode_active = false;
+
+        while let Some(msg) = rx.recv().await {
+            match msg {
+                AudioControlMessage::StartRecording { meeting_id, app_handle, reply } => {
+                    let result = audio.start_recording(&meeting_id, app_handle).await;
+                    if result.is_ok() {
+                        dual_mode_active = false;
+                    }
+                    publish(&status_tx_task, &audio, &dual_audio, dual_mode_active);
+                    let _ = reply.send(result);
+                }
+                AudioControlMessage::StopRecording { reply } => {
+                    let result = audio.stop_recording().await;
+                    publish(&status_tx_task, &audio, &dual_audio, dual_mode_active);
+                    let _ = reply.send(result);
+                }
+                AudioControlMessage::PauseRecording { reply } => {
+                    let result = audio.pause_recording().await;
+                    publish(&status_tx_task, &audio, &dual_audio, dual_mode_active);
+                    let _ = reply.send(result);
+                }
+                AudioControlMessage::ResumeRecording { reply } => {
+                    let result = audio.resume_recording().await;
+                    publish(&status_tx_task, &audio, &dual_audio, dual_mode_active);
+                    let _ = reply.send(result);
+                }
+                AudioControlMessage::SetDevice { device_index, reply } => {
+                    audio.set_device(device_index);
+                    let _ = reply.send(());
+                }
+                AudioControlMessage::GetSelectedDevice { reply } => {
+                    let _ = reply.send(audio.get_selected_device());
+                }
+                AudioControlMessage::SetLoopbackDevice { device_index, reply } => {
+                    audio.set_loopback_device(device_index);
+                    let _ = reply.send(());
+                }
+                AudioControlMessage::StartDual { meeting_id, mic_device_index, system_device_index, extra_device_indices, app_handle, reply } => {
+                    let result = dual_audio.start(&meeting_id, mic_device_index, system_device_index, extra_device_indices, app_handle);
+                    if result.is_ok() {
+                        dual_mode_active = true;
+                    }
+                    publish(&status_tx_task, &audio, &dual_audio, dual_mode_active);
+                    let _ = reply.send(result);
+                }
+                AudioControlMessage::StopDual { reply } => {
+                    let result = dual_audio.stop().await;
+                    dual_mode_active = false;
+                    publish(&status_tx_task, &audio, &dual_audio, dual_mode_active);
+                    let _ = reply.send(result);
+                }
+                AudioControlMessage::SetInputGain { gain, reply } => {
+                    audio.set_input_gain(gain);
+                    dual_audio.set_input_gain(gain);
+                    let _ = reply.send(());
+                }
+                AudioControlMessage::SetVadThresholds { silence_threshold, auto_pause_secs, reply } => {
+                    audio.set_vad_thresholds(silence_threshold, auto_pause_secs);
+                    dual_audio.set_vad_thresholds(silence_threshold, auto_pause_secs);
+                    let _ = reply.send(());
+                }
+                AudioControlMessage::SetClipHoldMs { clip_hold_ms, reply } => {
+                    audio.set_clip_hold_ms(clip_hold_ms);
+                    dual_audio.set_clip_hold_ms(clip_hold_ms);
+                    let _ = reply.send(());
+                }
+                AudioControlMessage::SetResamplerQuality { quality, reply } => {
+                    audio.set_resampler_quality(quality);
+                    dual_audio.set_resampler_quality(quality);
+                    let _ = reply.send(());
+                }
+                AudioControlMessage::MuteMic { muted, reply } => {
+                    dual_audio.mute_mic(muted);
+                    publish(&status_tx_task, &audio, &dual_audio, dual_mode_active);
+                    let _ = reply.send(());
+                }
+                AudioControlMessage::MuteSystem { muted, reply } => {
+                    dual_audio.mute_system(muted);
+                    publish(&status_tx_task, &audio, &dual_audio, dual_mode_active);
+                    let _ = reply.send(());
+                }
+                AudioControlMessage::GetStatus { reply } => {
+                    let _ = reply.send(current_status(&audio, &dual_audio, dual_mode_active));
+                }
+            }
+        }
+    });
+
+    (AudioActorHandle { tx }, status_tx)
+}
+
+fn current_status(audio: &AudioRecorder, dual_audio: &DualAudioRecorder, dual_mode_active: bool) -> AudioStatusMessage {
+    let dual_status = dual_audio.get_status();
+    let dual_is_recording = dual_status.get("is_recording").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    if dual_mode_active && dual_is_recording {
+        return AudioStatusMessage {
+            state: "recording".to_string(),
+            is_dual_mode: true,
+            meeting_id: dual_status.get("meeting_id").and_then(|v| v.as_str()).map(String::from),
+            mic_active: dual_status.get("mic_active").and_then(|v| v.as_bool()).unwrap_or(false),
+            system_active: dual_status.get("system_active").and_then(|v| v.as_bool()).unwrap_or(false),
+            mic_device: dual_status.get("mic_device").and_then(|v| v.as_str()).map(String::from),
+            system_device: dual_status.get("system_device").and_then(|v| v.as_str()).map(String::from),
+            mic_muted: dual_status.get("mic_muted").and_then(|v| v.as_bool()).unwrap_or(false),
+            system_muted: dual_status.get("system_muted").and_then(|v| v.as_bool()).unwrap_or(false),
+            pause_markers: Vec::new(),
+            paused_duration_secs: 0.0,
+        };
+    }
+
+    let legacy = audio.get_state();
+    let state_str = legacy.get("state").and_then(|v| v.as_str()).unwrap_or("idle").to_string();
+    AudioStatusMessage {
+        is_dual_mode: false,
+        meeting_id: legacy.get("meeting_id").and_then(|v| v.as_str()).map(String::from),
+        mic_active: state_str == "recording",
+        system_active: false,
+        mic_device: None,
+        system_device: None,
+        mic_muted: dual_status.get("mic_muted").and_then(|v| v.as_bool()).unwrap_or(false),
+        system_muted: dual_status.get("system_muted").and_then(|v| v.as_bool()).unwrap_or(false),
+        pause_markers: legacy
+            .get("pause_markers")
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_u64()).collect())
+            .unwrap_or_default(),
+        paused_duration_secs: legacy.get("paused_duration_secs").and_then(|v| v.as_f64()).unwrap_or(0.0),
+        state: state_str,
+    }
+}
+
+fn publish(
+    status_tx: &broadcast::Sender<AudioStatusMessage>,
+    audio: &AudioRecorder,
+    dual_audio: &DualAudioRecorder,
+    dual_mode_active: bool,
+) {
+    let _ = status_tx.send(current_status(audio, dual_audio, dual_mode_active));
+}
@@ -0,0 +1,43 @@
+use keyring::Entry;
+
+/// Service name under which all WizScribe secrets are filed in the
+/// platform secret store (Secret Service on Linux, Keychain on macOS,
+/// Credential Manager on Windows).
+const SERVICE_NAME: &str = "wizscribe";
+
+/// `config.json` never sees the secret itself, only `(provider, key_id)` -
+/// enough to look the entry back up in the keyring. There's one key per
+/// provider today, so `key_id` is just a fixed slot name, but it keeps the
+/// door open for multiple saved credentials per provider later.
+pub const DEFAULT_KEY_ID: &str = "default";
+
+fn entry_for(provider: &str, key_id: &str) -> anyhow::Result<Entry> {
+    Entry::new(SERVICE_NAME, &format!("{}:{}", provider, key_id))
+        .map_err(|e| anyhow::anyhow!("Failed to open system keyring entry: {}", e))
+}
+
+/// Stores `api_key` in the system keyring under `(provider, key_id)`.
+pub fn store_api_key(provider: &str, key_id: &str, api_key: &str) -> anyhow::Result<()> {
+    entry_for(provider, key_id)?
+        .set_password(api_key)
+        .map_err(|e| anyhow::anyhow!("Failed to store API key in system keyring: {}", e))
+}
+
+/// Fetches the API key for `(provider, key_id)`, or `None` if nothing has
+/// been stored yet (e.g. on a fresh machine or after the entry was deleted
+/// outside the app).
+pub fn load_api_key(provider: &str, key_id: &str) -> anyhow::Result<Option<String>> {
+    match entry_for(provider, key_id)?.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(anyhow::anyhow!("Failed to read API key from system keyring: {}", e)),
+    }
+}
+
+/// Removes the stored key for `(provider, key_id)`, if any.
+pub fn delete_api_key(provider: &str, key_id: &str) -> anyhow::Result<()> {
+    match entry_for(provider, key_id)?.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(anyhow::anyhow!("Failed to delete API key from system keyring: {}", e)),
+    }
+}
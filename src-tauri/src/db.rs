@@ -3,6 +3,37 @@ use serde::{Serialize, Deserialize};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use anyhow::Result;
+use tokio::sync::broadcast;
+
+/// Which table a `DbEvent` refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DbEntity {
+    Meeting,
+    Note,
+}
+
+/// What happened to the row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DbEventKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// Published by every mutating `Database` method except `create_note` (whose
+/// only caller, `create_meeting`, publishes the meeting's `Created` event
+/// instead) after its write commits, so
+/// the recording/transcription pipeline and the frontend bridge can react to
+/// a transcript/summary finishing without polling `list_meetings` - the
+/// in-process analogue of a `NOTIFY`/trigger fan-out.
+#[derive(Debug, Clone, Serialize)]
+pub struct DbEvent {
+    pub entity: DbEntity,
+    pub id: String,
+    pub kind: DbEventKind,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Meeting {
@@ -31,8 +62,20 @@ pub struct Template {
     pub structure: serde_json::Value,
 }
 
+/// One match from `Database::search`. `field` is `"transcript"`, `"summary"`,
+/// or `"note"`; a `"note"` hit's `meeting_id` can be looked up against that
+/// meeting's `Note.timestamps` to jump to the point in the audio it came from.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub meeting_id: String,
+    pub field: String,
+    pub snippet: String,
+    pub rank: f64,
+}
+
 pub struct Database {
     pool: Pool<Sqlite>,
+    events: broadcast::Sender<DbEvent>,
 }
 
 impl Database {
@@ -42,12 +85,25 @@ impl Database {
             .connect(db_path)
             .await?;
 
-        let db = Self { pool };
+        let (events, _) = broadcast::channel(64);
+        let db = Self { pool, events };
         db.init().await?;
-        
+
         Ok(db)
     }
 
+    /// Subscribes to change events for every mutation the database publishes.
+    /// Lagging receivers silently miss the oldest events instead of blocking
+    /// writers - fine here since callers only use this to trigger a refresh,
+    /// not to replay history.
+    pub fn subscribe(&self) -> broadcast::Receiver<DbEvent> {
+        self.events.subscribe()
+    }
+
+    fn publish(&self, entity: DbEntity, id: &str, kind: DbEventKind) {
+        let _ = self.events.send(DbEvent { entity, id: id.to_string(), kind });
+    }
+
     async fn init(&self) -> Result<()> {
         sqlx::query(
             r#"
@@ -92,6 +148,119 @@ impl Database {
         .execute(&self.pool)
         .await?;
 
+        // FTS5 index over meetings.transcript/summary and notes.content, kept
+        // in sync by the triggers below instead of at query time so `search`
+        // stays a single indexed lookup. `entity_id` is always a meeting id -
+        // a note's own id is irrelevant to the frontend, which only ever
+        // wants to jump to the meeting a hit came from.
+        sqlx::query(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(
+                entity UNINDEXED,
+                entity_id UNINDEXED,
+                field UNINDEXED,
+                content
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TRIGGER IF NOT EXISTS meetings_search_ai AFTER INSERT ON meetings BEGIN
+                INSERT INTO search_index(entity, entity_id, field, content) VALUES ('meeting', new.id, 'transcript', COALESCE(new.transcript, ''));
+                INSERT INTO search_index(entity, entity_id, field, content) VALUES ('meeting', new.id, 'summary', COALESCE(new.summary, ''));
+            END
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TRIGGER IF NOT EXISTS meetings_search_au AFTER UPDATE ON meetings BEGIN
+                DELETE FROM search_index WHERE entity = 'meeting' AND entity_id = new.id AND field IN ('transcript', 'summary');
+                INSERT INTO search_index(entity, entity_id, field, content) VALUES ('meeting', new.id, 'transcript', COALESCE(new.transcript, ''));
+                INSERT INTO search_index(entity, entity_id, field, content) VALUES ('meeting', new.id, 'summary', COALESCE(new.summary, ''));
+            END
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // Catches the meeting's own fields plus any note field indexed under
+        // the same entity_id, whether or not `ON DELETE CASCADE` also fired
+        // `notes_search_ad` below.
+        sqlx::query(
+            r#"
+            CREATE TRIGGER IF NOT EXISTS meetings_search_ad AFTER DELETE ON meetings BEGIN
+                DELETE FROM search_index WHERE entity_id = old.id;
+            END
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TRIGGER IF NOT EXISTS notes_search_ai AFTER INSERT ON notes BEGIN
+                INSERT INTO search_index(entity, entity_id, field, content) VALUES ('note', new.meeting_id, 'note', COALESCE(new.content, ''));
+            END
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TRIGGER IF NOT EXISTS notes_search_au AFTER UPDATE ON notes BEGIN
+                DELETE FROM search_index WHERE entity = 'note' AND entity_id = new.meeting_id AND field = 'note';
+                INSERT INTO search_index(entity, entity_id, field, content) VALUES ('note', new.meeting_id, 'note', COALESCE(new.content, ''));
+            END
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TRIGGER IF NOT EXISTS notes_search_ad AFTER DELETE ON notes BEGIN
+                DELETE FROM search_index WHERE entity = 'note' AND entity_id = old.meeting_id AND field = 'note';
+            END
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        // The triggers above only populate `search_index` going forward -
+        // they never fire for rows that already existed in `meetings`/`notes`
+        // before this virtual table was created, which would otherwise leave
+        // every pre-existing meeting/note permanently unsearchable. Guarded
+        // by an empty index so this only seeds once, on the upgrade that
+        // first creates the table, rather than re-inserting duplicates on
+        // every startup.
+        let indexed_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM search_index")
+            .fetch_one(&self.pool)
+            .await?;
+
+        if indexed_count == 0 {
+            sqlx::query(
+                r#"
+                INSERT INTO search_index(entity, entity_id, field, content)
+                SELECT 'meeting', id, 'transcript', COALESCE(transcript, '') FROM meetings
+                UNION ALL
+                SELECT 'meeting', id, 'summary', COALESCE(summary, '') FROM meetings
+                UNION ALL
+                SELECT 'note', n.meeting_id, 'note', COALESCE(n.content, '')
+                FROM notes n
+                WHERE EXISTS (SELECT 1 FROM meetings m WHERE m.id = n.meeting_id)
+                "#
+            )
+            .execute(&self.pool)
+            .await?;
+        }
+
         // Insert default templates
         self.insert_default_templates().await?;
 
@@ -148,6 +317,8 @@ impl Database {
         // Create empty note for this meeting
         self.create_note(&id).await?;
 
+        self.publish(DbEntity::Meeting, &id, DbEventKind::Created);
+
         Ok(Meeting {
             id,
             title: title.to_string(),
@@ -190,6 +361,23 @@ impl Database {
         .execute(&self.pool)
         .await?;
 
+        self.publish(DbEntity::Meeting, id, DbEventKind::Updated);
+
+        Ok(())
+    }
+
+    /// Clears a meeting's audio fields back to `NULL`, for when a recording
+    /// turned out to have no usable audio and its WAV file was discarded.
+    pub async fn clear_meeting_audio(&self, id: &str) -> Result<()> {
+        sqlx::query(
+            "UPDATE meetings SET audio_path = NULL, duration_secs = NULL WHERE id = ?"
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        self.publish(DbEntity::Meeting, id, DbEventKind::Updated);
+
         Ok(())
     }
 
@@ -202,6 +390,8 @@ impl Database {
         .execute(&self.pool)
         .await?;
 
+        self.publish(DbEntity::Meeting, id, DbEventKind::Updated);
+
         Ok(())
     }
 
@@ -214,6 +404,8 @@ impl Database {
         .execute(&self.pool)
         .await?;
 
+        self.publish(DbEntity::Meeting, id, DbEventKind::Updated);
+
         Ok(())
     }
 
@@ -223,6 +415,8 @@ impl Database {
             .execute(&self.pool)
             .await?;
 
+        self.publish(DbEntity::Meeting, id, DbEventKind::Deleted);
+
         Ok(())
     }
 
@@ -274,9 +468,32 @@ impl Database {
         .execute(&self.pool)
         .await?;
 
+        self.publish(DbEntity::Note, meeting_id, DbEventKind::Updated);
+
         Ok(())
     }
 
+    // Full-text search
+    pub async fn search(&self, query: &str) -> Result<Vec<SearchHit>> {
+        let rows = sqlx::query_as::<_, SearchHitRow>(
+            r#"
+            SELECT
+                entity_id AS meeting_id,
+                field,
+                snippet(search_index, 3, '<mark>', '</mark>', '...', 10) AS snippet,
+                bm25(search_index) AS rank
+            FROM search_index
+            WHERE search_index MATCH ?
+            ORDER BY rank
+            "#
+        )
+        .bind(query)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
     // Template operations
     pub async fn list_templates(&self) -> Result<Vec<Template>> {
         let rows = sqlx::query_as::<_, TemplateRow>(
@@ -365,3 +582,22 @@ impl From<TemplateRow> for Template {
         }
     }
 }
+
+#[derive(sqlx::FromRow)]
+struct SearchHitRow {
+    meeting_id: String,
+    field: String,
+    snippet: String,
+    rank: f64,
+}
+
+impl From<SearchHitRow> for SearchHit {
+    fn from(row: SearchHitRow) -> Self {
+        Self {
+            meeting_id: row.meeting_id,
+            field: row.field,
+            snippet: row.snippet,
+            rank: row.rank,
+        }
+    }
+}
@@ -1,21 +1,29 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::thread;
-use std::collections::VecDeque;
+use std::time::Instant;
+use std::collections::{HashMap, VecDeque};
 use hound::{WavSpec, WavWriter};
 use std::io::BufWriter;
 use std::fs::File;
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use crossbeam_channel::{bounded, Sender, Receiver};
+use tauri::Emitter;
 
-use crate::audio::{list_audio_devices, get_audio_duration};
+use crate::audio::{list_audio_devices, get_audio_duration, LiveResampler, ResamplerQuality};
+use crate::levels::{self, ChannelLevel};
+use crate::mixer::{self, ChannelBuffer, SourceChannel, OutputLayout, GainTable};
 
-/// Represents a single transcribed segment with speaker attribution
+/// Represents a single transcribed segment with speaker attribution.
+/// `speaker` is an arbitrary source label (e.g. "Me", "Them", or a
+/// participant/device name for setups with more than two sources) rather
+/// than one of a fixed pair - callers decide what to call each source when
+/// they build its segments.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpeakerSegment {
-    pub speaker: String, // "Me" or "Them"
+    pub speaker: String,
     pub text: String,
     pub start_ms: u64,
     pub end_ms: u64,
@@ -43,23 +51,12 @@ impl SpeakerTranscript {
         }
     }
 
-    /// Merge two transcripts (one per channel) with overlap detection
-    pub fn merge(
-        mut mic_segments: Vec<SpeakerSegment>,
-        mut system_segments: Vec<SpeakerSegment>,
-    ) -> Vec<SpeakerSegment> {
-        // Ensure segments are labeled correctly
-        for seg in &mut mic_segments {
-            seg.speaker = "Me".to_string();
-        }
-        for seg in &mut system_segments {
-            seg.speaker = "Them".to_string();
-        }
-
-        let mut all_segments: Vec<SpeakerSegment> = mic_segments
-            .into_iter()
-            .chain(system_segments)
-            .collect();
+    /// Merge transcripts from an arbitrary number of sources (one per
+    /// channel/track) with overlap detection. Each inner `Vec` is already
+    /// labeled with its own speaker by the caller; this only orders and
+    /// flags overlaps, it doesn't assume which or how many sources exist.
+    pub fn merge(sources: Vec<Vec<SpeakerSegment>>) -> Vec<SpeakerSegment> {
+        let mut all_segments: Vec<SpeakerSegment> = sources.into_iter().flatten().collect();
 
         // Sort by start time
         all_segments.sort_by_key(|s| s.start_ms);
@@ -79,6 +76,18 @@ impl SpeakerTranscript {
     }
 }
 
+/// The device-native capture config actually negotiated for a source,
+/// paired with the recorder's fixed output rate, so the UI can show e.g.
+/// "48000 Hz stereo f32 -> 16000 Hz mono" instead of silently resampling
+/// with no visibility into what the hardware is actually doing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NegotiatedConfig {
+    pub native_sample_rate: u32,
+    pub native_channels: u16,
+    pub native_sample_format: String,
+    pub target_sample_rate: u32,
+}
+
 /// Status returned when dual recording starts
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DualRecordingStatus {
@@ -87,6 +96,14 @@ pub struct DualRecordingStatus {
     pub system_active: bool,
     pub mic_device: String,
     pub system_device: String,
+    pub mic_config: NegotiatedConfig,
+    pub system_config: Option<NegotiatedConfig>,
+    /// One entry per extra (beyond mic/system) source actually opened, in
+    /// the same order as the `extra_device_indices` passed to `start` -
+    /// each is written to its own `{stem}_extraN.wav` side file rather than
+    /// into the mic/system stereo file (see `record_multi_streams`).
+    pub extra_active: Vec<bool>,
+    pub extra_devices: Vec<String>,
 }
 
 /// Result returned when dual recording stops
@@ -97,6 +114,47 @@ pub struct DualRecordingResult {
     pub is_dual_audio: bool,
     pub mic_captured: bool,
     pub system_captured: bool,
+    /// Offset between the mic and system streams' first captured buffer, in
+    /// milliseconds (positive means system audio started after the mic).
+    /// `None` unless both sources were captured.
+    pub sync_offset_ms: Option<i64>,
+    /// Largest absolute drift, in milliseconds, that either stream's most
+    /// recently aligned packet showed against that stream's own timeline,
+    /// sampled once per writer tick. `None` unless both sources were
+    /// captured.
+    pub max_drift_ms: Option<i64>,
+    /// Path to a mono, analysis-ready mixdown of the mic and system channels
+    /// (see `mixer::mix`), written alongside the stereo file. `None` unless
+    /// both sources were captured - a single-source recording already is
+    /// the analysis track.
+    pub mixed_path: Option<String>,
+    /// Total samples dropped across every source (mic, system and any
+    /// extras) because the writer thread fell behind and a source's ring
+    /// buffer was full. Zero means a clean recording; anything higher points
+    /// at a CPU-starved capture.
+    pub overrun_samples: u64,
+    /// Total samples of silence synthesized across every source to paper
+    /// over a side that fell behind the shared timeline (see `TimelineTrack`).
+    pub underrun_samples: u64,
+}
+
+/// Measured synchronization quality between the mic and system streams,
+/// updated live by the writer thread as it aligns buffers from each side
+/// onto a shared timeline. Read back by `DualAudioRecorder::stop` to
+/// populate `DualRecordingResult`.
+#[derive(Debug, Clone, Copy, Default)]
+struct SyncStats {
+    offset_ms: i64,
+    max_drift_ms: i64,
+}
+
+/// Cumulative ring-buffer health across all of a mixer's sources, updated
+/// live by the writer thread. Read back by `DualAudioRecorder::stop` to
+/// populate `DualRecordingResult`.
+#[derive(Debug, Clone, Copy, Default)]
+struct IoStats {
+    overrun_samples: u64,
+    underrun_samples: u64,
 }
 
 /// Audio source error event payload
@@ -108,6 +166,21 @@ pub struct AudioSourceError {
     pub recording_continues: bool,
 }
 
+/// Emits an `audio-source-error` event after a capture callback's ring buffer
+/// overran and had to drop a packet. Recording itself is unaffected - this is
+/// purely so the UI can tell the user a source is under-resourced.
+fn emit_overrun_error(app_handle: &tauri::AppHandle, source: &str, lost_samples: usize) {
+    let _ = app_handle.emit(
+        "audio-source-error",
+        AudioSourceError {
+            source: source.to_string(),
+            error: format!("Ring buffer full, dropped {} samples", lost_samples),
+            timestamp: levels::now_ms(),
+            recording_continues: true,
+        },
+    );
+}
+
 
 /// Writes interleaved stereo samples to a WAV file
 pub struct StereoWavWriter {
@@ -181,6 +254,286 @@ impl StereoWavWriter {
     }
 }
 
+/// Gap between a chunk's declared timeline position and where a track
+/// already is that's still treated as ordinary jitter rather than a real
+/// dropout - below this, no silence is inserted and no samples are trimmed.
+const SYNC_JITTER_SECS: f64 = 0.02;
+
+/// Largest gap that gets padded with silence; beyond this a side is
+/// considered to have genuinely dropped out rather than merely drifted, so
+/// padding is capped to avoid writing minutes of silence into the file.
+const MAX_SYNC_GAP_SECS: f64 = 2.0;
+
+/// Tracks one side's (mic or system) position on the shared recording
+/// timeline so the writer thread can align buffers from both streams
+/// instead of interleaving whatever happens to be in each channel at flush
+/// time. Every incoming chunk is tagged with a capture timestamp (elapsed
+/// time since a shared epoch, not device sample count, since the two
+/// streams can drift at slightly different effective rates); `align`
+/// inserts silence for a side that has fallen behind and trims samples that
+/// would duplicate audio already queued when a side runs ahead.
+struct TimelineTrack {
+    sample_rate: u32,
+    /// Timeline position, in seconds since the shared epoch, of the next
+    /// sample this track will append.
+    position_secs: f64,
+    started: bool,
+    /// Timeline position of this track's very first aligned chunk, in
+    /// seconds since the shared epoch - the actual start-time measurement,
+    /// as opposed to `last_drift_secs` which is per-packet jitter measured
+    /// after the stream is already running.
+    first_chunk_secs: f64,
+    /// Total samples of silence synthesized to catch this track up to the
+    /// shared timeline - an underrun, in the sense that at that point there
+    /// wasn't real audio available to fill the gap.
+    underrun_samples: u64,
+}
+
+impl TimelineTrack {
+    fn new(sample_rate: u32) -> Self {
+        Self { sample_rate, position_secs: 0.0, started: false, first_chunk_secs: 0.0, underrun_samples: 0 }
+    }
+
+    /// Aligns `samples` (captured at `chunk_secs` since the shared epoch)
+    /// onto this track's timeline, returning the samples to actually append
+    /// and the drift (in seconds, positive = this side has fallen behind,
+    /// i.e. the chunk's timestamp is later than where the track had reached)
+    /// observed before any correction was applied.
+    fn align(&mut self, chunk_secs: f64, samples: Vec<f32>) -> (Vec<f32>, f64) {
+        if !self.started {
+            self.started = true;
+            self.first_chunk_secs = chunk_secs;
+            self.position_secs = chunk_secs;
+            self.position_secs += samples.len() as f64 / self.sample_rate as f64;
+            return (samples, 0.0);
+        }
+
+        let drift = chunk_secs - self.position_secs;
+        let mut output = samples;
+
+        if drift > SYNC_JITTER_SECS {
+            // This side is behind the shared timeline: pad with silence to
+            // catch up before appending the new audio.
+            let pad_secs = drift.min(MAX_SYNC_GAP_SECS);
+            let pad_len = (pad_secs * self.sample_rate as f64) as usize;
+            let mut padded = vec![0.0; pad_len];
+            padded.append(&mut output);
+            output = padded;
+            self.position_secs += pad_secs;
+            self.underrun_samples += pad_len as u64;
+        } else if drift < -SYNC_JITTER_SECS {
+            // This side is ahead: it's producing audio for a time that's
+            // already been written, so drop the overlap instead of
+            // duplicating it.
+            let overlap_len = ((-drift) * self.sample_rate as f64) as usize;
+            if overlap_len < output.len() {
+                output.drain(..overlap_len);
+            } else {
+                output.clear();
+            }
+        }
+
+        self.position_secs += output.len() as f64 / self.sample_rate as f64;
+
+        (output, drift)
+    }
+}
+
+/// Default number of aligned samples an `AudioMixer` pulls from a source per
+/// tick, used unless `DualAudioRecorder::set_writer_frame_size` overrides it.
+/// Chosen to be a few capture callbacks' worth at typical device buffer
+/// sizes, so a tick neither busy-spins on near-empty sources nor holds back
+/// audio for long once it's queued.
+const DEFAULT_MIXER_FRAME_SIZE: usize = 2048;
+
+/// Default ring buffer capacity (in queued packets, not samples) for a
+/// source, used unless `DualAudioRecorder::set_ring_buffer_capacity`
+/// overrides it.
+const DEFAULT_RING_BUFFER_CAPACITY: usize = 100;
+
+/// Cloneable handle capture callbacks push captured packets through. Wraps
+/// the sending half of a source's ring buffer so a full buffer - the writer
+/// thread falling behind the capture rate - is counted as an overrun instead
+/// of silently discarding audio the way a bare `try_send` would.
+#[derive(Clone)]
+pub struct SourceSender {
+    label: String,
+    tx: Sender<(f64, Vec<f32>)>,
+    overrun_samples: Arc<AtomicU64>,
+}
+
+impl SourceSender {
+    /// Pushes a captured, timestamped packet. Returns the number of samples
+    /// dropped if the ring buffer was full.
+    pub fn push(&self, timestamp_secs: f64, samples: Vec<f32>) -> Option<usize> {
+        let len = samples.len();
+        match self.tx.try_send((timestamp_secs, samples)) {
+            Ok(()) => None,
+            Err(_) => {
+                self.overrun_samples.fetch_add(len as u64, Ordering::Relaxed);
+                Some(len)
+            }
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+/// One input feeding an `AudioMixer`: a capture-thread-to-writer-thread
+/// handoff queue (the "ring buffer", built on the same bounded
+/// `crossbeam_channel` the two-source recorder already used for this), its
+/// own resampler, and its own `TimelineTrack` so sources with different
+/// native sample rates - or that drop out independently, the way
+/// `mic_active`/`system_active` already can - are each handled without
+/// assuming exactly two of them exist.
+pub struct AudioSource {
+    pub label: String,
+    tx: Sender<(f64, Vec<f32>)>,
+    rx: Receiver<(f64, Vec<f32>)>,
+    resampler: Arc<std::sync::Mutex<LiveResampler>>,
+    pub active: Arc<AtomicBool>,
+    track: TimelineTrack,
+    carry: Vec<f32>,
+    last_drift_secs: f64,
+    overrun_samples: Arc<AtomicU64>,
+}
+
+impl AudioSource {
+    pub fn new(
+        label: impl Into<String>,
+        native_rate: u32,
+        target_rate: u32,
+        active: Arc<AtomicBool>,
+        ring_buffer_capacity: usize,
+        resampler_quality: ResamplerQuality,
+    ) -> anyhow::Result<Self> {
+        let (tx, rx) = bounded(ring_buffer_capacity);
+        Ok(Self {
+            label: label.into(),
+            tx,
+            rx,
+            resampler: Arc::new(std::sync::Mutex::new(LiveResampler::new(resampler_quality, native_rate, target_rate)?)),
+            active,
+            track: TimelineTrack::new(target_rate),
+            carry: Vec::new(),
+            last_drift_secs: 0.0,
+            overrun_samples: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Handle capture callbacks use to push timestamped packets into this
+    /// source's ring buffer. Cheap to clone.
+    pub fn sender(&self) -> SourceSender {
+        SourceSender {
+            label: self.label.clone(),
+            tx: self.tx.clone(),
+            overrun_samples: Arc::clone(&self.overrun_samples),
+        }
+    }
+
+    pub fn resampler(&self) -> Arc<std::sync::Mutex<LiveResampler>> {
+        Arc::clone(&self.resampler)
+    }
+
+    pub fn started(&self) -> bool {
+        self.track.started
+    }
+
+    /// Drift (seconds, positive = this source has fallen behind its
+    /// timeline) measured the last time a packet from this source was
+    /// aligned onto its timeline.
+    pub fn last_drift_secs(&self) -> f64 {
+        self.last_drift_secs
+    }
+
+    /// Timeline position of this source's very first aligned chunk, in
+    /// seconds since the shared epoch. Meaningless until `started()` is true.
+    pub fn first_chunk_secs(&self) -> f64 {
+        self.track.first_chunk_secs
+    }
+
+    /// Total samples dropped so far because this source's ring buffer was
+    /// full when a capture callback tried to push into it.
+    pub fn overrun_samples(&self) -> u64 {
+        self.overrun_samples.load(Ordering::Relaxed)
+    }
+
+    /// Total samples of silence synthesized so far to catch this source up
+    /// to the shared timeline.
+    pub fn underrun_samples(&self) -> u64 {
+        self.track.underrun_samples
+    }
+
+    /// Drains every packet currently queued, aligns each onto this source's
+    /// timeline, and accumulates the result into the carry buffer. Returns a
+    /// fixed-size `frame_size` frame once enough has accumulated, leaving any
+    /// remainder for the next call rather than emitting partial frames.
+    fn pull_frame(&mut self, frame_size: usize) -> Option<Vec<f32>> {
+        while let Ok((ts, samples)) = self.rx.try_recv() {
+            let (aligned, drift) = self.track.align(ts, samples);
+            self.last_drift_secs = drift;
+            self.carry.extend(aligned);
+        }
+
+        if self.carry.len() >= frame_size {
+            Some(self.carry.drain(..frame_size).collect())
+        } else {
+            None
+        }
+    }
+
+    /// Flushes whatever is left in the carry buffer, short of a full frame.
+    /// Called once, after the source's capture stream has stopped.
+    fn drain_remainder(&mut self) -> Vec<f32> {
+        std::mem::take(&mut self.carry)
+    }
+}
+
+/// Sums/lays out an arbitrary number of `AudioSource`s - the generalization
+/// of the mic+system pair `record_dual_streams` used to hard-code. Each
+/// source keeps its own ring buffer, resample ratio and timeline, so this
+/// scales to e.g. two microphones plus a system loopback device, each
+/// mapped to its own output channel or mixed down, without the writer
+/// thread needing to know how many sources there are ahead of time.
+pub struct AudioMixer {
+    sources: Vec<AudioSource>,
+    frame_size: usize,
+}
+
+impl AudioMixer {
+    pub fn new(sources: Vec<AudioSource>, frame_size: usize) -> Self {
+        Self { sources, frame_size }
+    }
+
+    pub fn sources(&self) -> &[AudioSource] {
+        &self.sources
+    }
+
+    /// One tick: pulls a frame from every source that has enough queued,
+    /// labeled by source. Sources with nothing ready yet (e.g. momentarily
+    /// inactive) are simply absent from the result; callers that need a
+    /// fixed channel layout (e.g. stereo WAV) pad for a missing side
+    /// themselves, the way `record_multi_streams` does via
+    /// `StereoWavWriter::flush_buffers`.
+    pub fn tick(&mut self) -> Vec<(String, Vec<f32>)> {
+        self.sources
+            .iter_mut()
+            .filter_map(|s| s.pull_frame(self.frame_size).map(|frame| (s.label.clone(), frame)))
+            .collect()
+    }
+
+    /// Drains every source's leftover partial frame. Called once, after all
+    /// of a mixer's sources have stopped capturing.
+    pub fn drain_remainder(&mut self) -> Vec<(String, Vec<f32>)> {
+        self.sources
+            .iter_mut()
+            .map(|s| (s.label.clone(), s.drain_remainder()))
+            .collect()
+    }
+}
+
 /// Auto-detect default microphone and system audio devices
 pub fn get_default_devices() -> (Option<usize>, Option<usize>) {
     let devices = match list_audio_devices() {
@@ -205,6 +558,54 @@ fn get_device_by_index(index: usize) -> anyhow::Result<cpal::Device> {
         .ok_or_else(|| anyhow::anyhow!("Device with index {} not found", index))
 }
 
+/// One contiguous range of input configs a device's driver advertises, as
+/// returned by `cpal::Device::supported_input_configs()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceConfigRange {
+    pub min_sample_rate: u32,
+    pub max_sample_rate: u32,
+    pub channels: u16,
+    pub sample_format: String,
+}
+
+/// What a device's driver actually supports, probed ahead of opening a
+/// stream so the caller can tell whether a device can reach the recorder's
+/// target sample rate cleanly instead of finding out only after
+/// `default_input_config()` picked something else and resampling quality
+/// suffered silently.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceCapabilities {
+    pub ranges: Vec<DeviceConfigRange>,
+    pub default_sample_rate: u32,
+    pub default_channels: u16,
+    pub default_sample_format: String,
+}
+
+/// Walks a device's supported input config ranges and its negotiated
+/// default, for the front end to display before recording starts.
+pub fn probe_device(index: usize) -> anyhow::Result<DeviceCapabilities> {
+    let device = get_device_by_index(index)?;
+
+    let ranges = device
+        .supported_input_configs()?
+        .map(|r| DeviceConfigRange {
+            min_sample_rate: r.min_sample_rate().0,
+            max_sample_rate: r.max_sample_rate().0,
+            channels: r.channels(),
+            sample_format: format!("{:?}", r.sample_format()),
+        })
+        .collect();
+
+    let default_config = device.default_input_config()?;
+
+    Ok(DeviceCapabilities {
+        ranges,
+        default_sample_rate: default_config.sample_rate().0,
+        default_channels: default_config.channels(),
+        default_sample_format: format!("{:?}", default_config.sample_format()),
+    })
+}
+
 /// Coordinates dual audio stream recording
 pub struct DualAudioRecorder {
     audio_dir: PathBuf,
@@ -214,7 +615,24 @@ pub struct DualAudioRecorder {
     system_active: Arc<AtomicBool>,
     mic_device_name: String,
     system_device_name: String,
+    /// One entry per extra source opened by the most recent `start`, beyond
+    /// the fixed mic/system pair - see `record_multi_streams`.
+    extra_active: Vec<Arc<AtomicBool>>,
+    extra_device_names: Vec<String>,
     sample_rate: u32,
+    input_gain: Arc<AtomicU32>,
+    clip_hold_ms: u64,
+    mic_levels: Arc<ChannelLevel>,
+    system_levels: Arc<ChannelLevel>,
+    silence_threshold: f32,
+    auto_pause_secs: f32,
+    mic_muted: Arc<AtomicBool>,
+    system_muted: Arc<AtomicBool>,
+    sync_stats: Arc<std::sync::Mutex<SyncStats>>,
+    io_stats: Arc<std::sync::Mutex<IoStats>>,
+    ring_buffer_capacity: usize,
+    writer_frame_size: usize,
+    resampler_quality: ResamplerQuality,
 }
 
 impl DualAudioRecorder {
@@ -227,10 +645,71 @@ impl DualAudioRecorder {
             system_active: Arc::new(AtomicBool::new(false)),
             mic_device_name: String::new(),
             system_device_name: String::new(),
+            extra_active: Vec::new(),
+            extra_device_names: Vec::new(),
             sample_rate: 16000,
+            input_gain: Arc::new(levels::gain_atomic(1.0)),
+            clip_hold_ms: 500,
+            mic_levels: Arc::new(ChannelLevel::default()),
+            system_levels: Arc::new(ChannelLevel::default()),
+            silence_threshold: 0.02,
+            auto_pause_secs: 3.0,
+            mic_muted: Arc::new(AtomicBool::new(false)),
+            system_muted: Arc::new(AtomicBool::new(false)),
+            sync_stats: Arc::new(std::sync::Mutex::new(SyncStats::default())),
+            io_stats: Arc::new(std::sync::Mutex::new(IoStats::default())),
+            ring_buffer_capacity: DEFAULT_RING_BUFFER_CAPACITY,
+            writer_frame_size: DEFAULT_MIXER_FRAME_SIZE,
+            resampler_quality: ResamplerQuality::Sinc,
         }
     }
 
+    pub fn set_input_gain(&mut self, gain: f32) {
+        levels::store_gain(&self.input_gain, gain);
+    }
+
+    pub fn set_clip_hold_ms(&mut self, clip_hold_ms: u64) {
+        self.clip_hold_ms = clip_hold_ms;
+    }
+
+    pub fn set_vad_thresholds(&mut self, silence_threshold: f32, auto_pause_secs: f32) {
+        self.silence_threshold = silence_threshold;
+        self.auto_pause_secs = auto_pause_secs;
+    }
+
+    /// Capacity (in queued packets, not samples) of each source's ring
+    /// buffer. Larger values tolerate longer writer-thread stalls before an
+    /// overrun starts dropping samples, at the cost of more latency-worth of
+    /// audio held in memory.
+    pub fn set_ring_buffer_capacity(&mut self, capacity: usize) {
+        self.ring_buffer_capacity = capacity;
+    }
+
+    /// Number of samples the writer thread pulls from each source per tick.
+    pub fn set_writer_frame_size(&mut self, frame_size: usize) {
+        self.writer_frame_size = frame_size;
+    }
+
+    /// Selects which resampler each source's capture stream builds; takes
+    /// effect on the following `start`.
+    pub fn set_resampler_quality(&mut self, quality: ResamplerQuality) {
+        self.resampler_quality = quality;
+    }
+
+    /// Mutes or unmutes the mic channel mid-recording. The capture callback
+    /// keeps writing silence rather than dropping samples, so the stereo
+    /// stream stays length-aligned for `split_stereo_channels` downstream.
+    /// Independent of `system_muted`, so unmuting never touches a channel
+    /// the user didn't explicitly silence.
+    pub fn mute_mic(&mut self, muted: bool) {
+        self.mic_muted.store(muted, Ordering::SeqCst);
+    }
+
+    /// Mutes or unmutes the system-audio channel mid-recording. See `mute_mic`.
+    pub fn mute_system(&mut self, muted: bool) {
+        self.system_muted.store(muted, Ordering::SeqCst);
+    }
+
     /// Start recording from both microphone and system audio sources
     /// System audio is optional - if not available, records mic only
     pub fn start(
@@ -238,6 +717,8 @@ impl DualAudioRecorder {
         meeting_id: &str,
         mic_device_index: Option<usize>,
         system_device_index: Option<usize>,
+        extra_device_indices: Vec<usize>,
+        app_handle: tauri::AppHandle,
     ) -> anyhow::Result<DualRecordingStatus> {
         if self.is_recording.load(Ordering::SeqCst) {
             return Err(anyhow::anyhow!("Already recording"));
@@ -254,13 +735,48 @@ impl DualAudioRecorder {
         // Get mic device (required)
         let mic_device = get_device_by_index(mic_idx)?;
         self.mic_device_name = mic_device.name().unwrap_or_else(|_| "Unknown Mic".to_string());
+        let mic_native_config = mic_device.default_input_config()?;
+        if mic_native_config.sample_rate().0 % self.sample_rate != 0 {
+            eprintln!(
+                "Warning: mic device '{}' has no integer-ratio rate to {} Hz (native {} Hz) - resampling quality may suffer",
+                self.mic_device_name, self.sample_rate, mic_native_config.sample_rate().0
+            );
+        }
+        let mic_config = NegotiatedConfig {
+            native_sample_rate: mic_native_config.sample_rate().0,
+            native_channels: mic_native_config.channels(),
+            native_sample_format: format!("{:?}", mic_native_config.sample_format()),
+            target_sample_rate: self.sample_rate,
+        };
 
         // Get system device (optional)
+        let mut system_config = None;
         let has_system_audio = if let Some(idx) = system_idx {
             match get_device_by_index(idx) {
                 Ok(device) => {
                     self.system_device_name = device.name().unwrap_or_else(|_| "Unknown System".to_string());
-                    true
+                    match device.default_input_config() {
+                        Ok(cfg) => {
+                            if cfg.sample_rate().0 % self.sample_rate != 0 {
+                                eprintln!(
+                                    "Warning: system device '{}' has no integer-ratio rate to {} Hz (native {} Hz) - resampling quality may suffer",
+                                    self.system_device_name, self.sample_rate, cfg.sample_rate().0
+                                );
+                            }
+                            system_config = Some(NegotiatedConfig {
+                                native_sample_rate: cfg.sample_rate().0,
+                                native_channels: cfg.channels(),
+                                native_sample_format: format!("{:?}", cfg.sample_format()),
+                                target_sample_rate: self.sample_rate,
+                            });
+                            true
+                        }
+                        Err(e) => {
+                            eprintln!("System audio device config not available: {}", e);
+                            self.system_device_name = "Not available".to_string();
+                            false
+                        }
+                    }
                 }
                 Err(e) => {
                     eprintln!("System audio device not available: {}", e);
@@ -274,6 +790,25 @@ impl DualAudioRecorder {
             false
         };
 
+        // Extra sources beyond mic/system: each gets its own capture thread
+        // and `{stem}_extraN.wav` side file (see `record_multi_streams`)
+        // instead of either being silently dropped or forced into the fixed
+        // mic/system pair. A device that fails to open is skipped with a
+        // warning rather than aborting the whole recording, matching how a
+        // missing system-audio device degrades to mic-only above.
+        let mut extra_resolved: Vec<(usize, String)> = Vec::new();
+        for &idx in &extra_device_indices {
+            match get_device_by_index(idx) {
+                Ok(device) => {
+                    let name = device.name().unwrap_or_else(|_| format!("Unknown device {}", idx));
+                    extra_resolved.push((idx, name));
+                }
+                Err(e) => eprintln!("Extra audio source {} not available, skipping: {}", idx, e),
+            }
+        }
+        self.extra_active = extra_resolved.iter().map(|_| Arc::new(AtomicBool::new(true))).collect();
+        self.extra_device_names = extra_resolved.iter().map(|(_, name)| name.clone()).collect();
+
         self.current_meeting_id = Some(meeting_id.to_string());
         self.is_recording.store(true, Ordering::SeqCst);
         self.mic_active.store(true, Ordering::SeqCst);
@@ -286,20 +821,67 @@ impl DualAudioRecorder {
         let mic_active = Arc::clone(&self.mic_active);
         let system_active = Arc::clone(&self.system_active);
         let target_sample_rate = self.sample_rate;
+        let input_gain = Arc::clone(&self.input_gain);
+        let clip_hold_ms = self.clip_hold_ms;
+        let mic_levels = Arc::clone(&self.mic_levels);
+        let system_levels = Arc::clone(&self.system_levels);
+        let mic_muted = Arc::clone(&self.mic_muted);
+        let system_muted = Arc::clone(&self.system_muted);
+
+        *self.sync_stats.lock().unwrap() = SyncStats::default();
+        let sync_stats = Arc::clone(&self.sync_stats);
+        *self.io_stats.lock().unwrap() = IoStats::default();
+        let io_stats = Arc::clone(&self.io_stats);
+        let capture_start = Instant::now();
+        let ring_buffer_capacity = self.ring_buffer_capacity;
+        let writer_frame_size = self.writer_frame_size;
+        let resampler_quality = self.resampler_quality;
+        let error_app_handle = app_handle.clone();
 
         let audio_path_clone = audio_path.clone();
+        let extra_sources: Vec<(usize, Arc<AtomicBool>)> = extra_resolved
+            .iter()
+            .map(|(idx, _)| *idx)
+            .zip(self.extra_active.iter().cloned())
+            .collect();
+
+        levels::spawn_level_emitter(
+            app_handle,
+            Arc::clone(&self.mic_levels),
+            if has_system_audio { Some(Arc::clone(&self.system_levels)) } else { None },
+            Arc::clone(&self.is_recording),
+            Some(levels::VadConfig {
+                silence_threshold: self.silence_threshold,
+                auto_pause_secs: self.auto_pause_secs,
+            }),
+        );
 
         // Spawn recording in a separate thread
+        let has_extra_sources = !extra_sources.is_empty();
         thread::spawn(move || {
-            let result = if has_system_audio {
-                record_dual_streams(
+            let result = if has_system_audio || has_extra_sources {
+                record_multi_streams(
                     audio_path_clone,
                     target_sample_rate,
                     is_recording,
                     mic_active,
                     system_active,
                     mic_idx,
-                    system_idx.unwrap(),
+                    has_system_audio.then_some(system_idx.unwrap()),
+                    extra_sources,
+                    input_gain,
+                    clip_hold_ms,
+                    mic_levels,
+                    system_levels,
+                    mic_muted,
+                    system_muted,
+                    capture_start,
+                    sync_stats,
+                    io_stats,
+                    ring_buffer_capacity,
+                    writer_frame_size,
+                    error_app_handle,
+                    resampler_quality,
                 )
             } else {
                 record_mono_stream(
@@ -307,6 +889,11 @@ impl DualAudioRecorder {
                     target_sample_rate,
                     is_recording,
                     mic_idx,
+                    input_gain,
+                    clip_hold_ms,
+                    mic_levels,
+                    mic_muted,
+                    resampler_quality,
                 )
             };
 
@@ -321,6 +908,10 @@ impl DualAudioRecorder {
             system_active: has_system_audio,
             mic_device: self.mic_device_name.clone(),
             system_device: self.system_device_name.clone(),
+            mic_config,
+            system_config,
+            extra_active: self.extra_active.iter().map(|_| true).collect(),
+            extra_devices: self.extra_device_names.clone(),
         })
     }
 
@@ -352,12 +943,33 @@ impl DualAudioRecorder {
         self.mic_active.store(false, Ordering::SeqCst);
         self.system_active.store(false, Ordering::SeqCst);
 
+        let is_dual_audio = mic_captured && system_captured;
+        let sync_stats = *self.sync_stats.lock().unwrap();
+        let io_stats = *self.io_stats.lock().unwrap();
+
+        let mixed_path = if is_dual_audio {
+            match write_mixdown(&audio_path) {
+                Ok(path) => Some(path.to_string_lossy().to_string()),
+                Err(e) => {
+                    eprintln!("Failed to write mixed-down track: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(DualRecordingResult {
             meeting_id,
             duration_secs: duration,
-            is_dual_audio: mic_captured && system_captured,
+            is_dual_audio,
             mic_captured,
             system_captured,
+            sync_offset_ms: is_dual_audio.then_some(sync_stats.offset_ms),
+            max_drift_ms: is_dual_audio.then_some(sync_stats.max_drift_ms),
+            overrun_samples: io_stats.overrun_samples,
+            underrun_samples: io_stats.underrun_samples,
+            mixed_path,
         })
     }
 
@@ -369,16 +981,73 @@ impl DualAudioRecorder {
             "mic_device": self.mic_device_name,
             "system_device": self.system_device_name,
             "meeting_id": self.current_meeting_id,
+            "mic_muted": self.mic_muted.load(Ordering::SeqCst),
+            "system_muted": self.system_muted.load(Ordering::SeqCst),
+            "extra_active": self.extra_active.iter().map(|a| a.load(Ordering::SeqCst)).collect::<Vec<_>>(),
+            "extra_devices": self.extra_device_names,
         })
     }
 }
 
+/// Reads back the interleaved stereo WAV a dual recording just wrote (mic
+/// left, system right) and mixes it down to a single mono, analysis-ready
+/// track via `mixer::mix`, instead of leaving the mixdown to whatever
+/// transcribes it later. Written as `{stem}_mixed.wav` next to `stereo_path`.
+fn write_mixdown(stereo_path: &Path) -> anyhow::Result<PathBuf> {
+    let mut reader = hound::WavReader::open(stereo_path)?;
+    let spec = reader.spec();
+
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Int => reader
+            .samples::<i16>()
+            .map(|s| s.map(|v| v as f32 / 32768.0))
+            .collect::<Result<_, _>>()?,
+        hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<_, _>>()?,
+    };
+
+    let mut mic = Vec::with_capacity(samples.len() / 2);
+    let mut system = Vec::with_capacity(samples.len() / 2);
+    for frame in samples.chunks_exact(2) {
+        mic.push(frame[0]);
+        system.push(frame[1]);
+    }
+
+    let streams = [
+        ChannelBuffer::new(SourceChannel::Mic, mic),
+        ChannelBuffer::new(SourceChannel::SystemLeft, system),
+    ];
+    let mixed = mixer::mix(&streams, OutputLayout::Mono, &GainTable::new());
+
+    let mixed_path = stereo_path.with_file_name(format!(
+        "{}_mixed.wav",
+        stereo_path.file_stem().and_then(|s| s.to_str()).unwrap_or("output")
+    ));
+    let mixed_spec = WavSpec {
+        channels: 1,
+        sample_rate: spec.sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = WavWriter::create(&mixed_path, mixed_spec)?;
+    for sample in mixed {
+        writer.write_sample((sample.max(-1.0).min(1.0) * 32767.0) as i16)?;
+    }
+    writer.finalize()?;
+
+    Ok(mixed_path)
+}
+
 /// Record from a single microphone into a mono WAV file
 fn record_mono_stream(
     output_path: PathBuf,
     target_sample_rate: u32,
     is_recording: Arc<AtomicBool>,
     mic_device_index: usize,
+    input_gain: Arc<AtomicU32>,
+    clip_hold_ms: u64,
+    mic_levels: Arc<ChannelLevel>,
+    mic_muted: Arc<AtomicBool>,
+    resampler_quality: ResamplerQuality,
 ) -> anyhow::Result<()> {
     let mic_device = get_device_by_index(mic_device_index)?;
 
@@ -404,7 +1073,15 @@ fn record_mono_stream(
 
     let wav_writer_clone = Arc::clone(&wav_writer);
     let is_recording_clone = Arc::clone(&is_recording);
-    let resample_ratio = target_sample_rate as f64 / mic_sample_rate as f64;
+    let resampler = Arc::new(std::sync::Mutex::new(LiveResampler::new(resampler_quality, mic_sample_rate, target_sample_rate)?));
+    let resampler_f32 = Arc::clone(&resampler);
+    let resampler_i16 = Arc::clone(&resampler);
+    let input_gain_f32 = Arc::clone(&input_gain);
+    let mic_levels_f32 = Arc::clone(&mic_levels);
+    let mic_muted_f32 = Arc::clone(&mic_muted);
+    let input_gain_i16 = Arc::clone(&input_gain);
+    let mic_levels_i16 = Arc::clone(&mic_levels);
+    let mic_muted_i16 = Arc::clone(&mic_muted);
 
     let stream = match mic_config.sample_format() {
         cpal::SampleFormat::F32 => {
@@ -422,8 +1099,22 @@ fn record_mono_stream(
                         data.to_vec()
                     };
 
+                    let gain = levels::load_gain(&input_gain_f32);
+                    let (rms, peak) = levels::rms_and_peak(&mono, gain);
+                    mic_levels_f32.update(rms, peak, clip_hold_ms);
+                    // Muted channels still flow as silence so the file stays
+                    // the same length instead of dropping samples.
+                    let mono: Vec<f32> = if mic_muted_f32.load(Ordering::SeqCst) {
+                        vec![0.0; mono.len()]
+                    } else {
+                        mono.into_iter().map(|s| s * gain).collect()
+                    };
+
                     // Resample
-                    let resampled = resample_linear(&mono, resample_ratio);
+                    let resampled = match resampler_f32.lock() {
+                        Ok(mut r) => r.process(&mono),
+                        Err(_) => Vec::new(),
+                    };
 
                     // Write to WAV
                     if let Ok(mut guard) = wav_writer_clone.lock() {
@@ -456,7 +1147,19 @@ fn record_mono_stream(
                         data.iter().map(|&s| s as f32 / 32768.0).collect()
                     };
 
-                    let resampled = resample_linear(&mono, resample_ratio);
+                    let gain = levels::load_gain(&input_gain_i16);
+                    let (rms, peak) = levels::rms_and_peak(&mono, gain);
+                    mic_levels_i16.update(rms, peak, clip_hold_ms);
+                    let mono: Vec<f32> = if mic_muted_i16.load(Ordering::SeqCst) {
+                        vec![0.0; mono.len()]
+                    } else {
+                        mono.into_iter().map(|s| s * gain).collect()
+                    };
+
+                    let resampled = match resampler_i16.lock() {
+                        Ok(mut r) => r.process(&mono),
+                        Err(_) => Vec::new(),
+                    };
 
                     if let Ok(mut guard) = wav_writer_clone.lock() {
                         if let Some(ref mut writer) = *guard {
@@ -485,8 +1188,18 @@ fn record_mono_stream(
 
     drop(stream);
 
+    // Drain the resampler's group delay so the tail of the recording isn't lost
+    let flushed = resampler.lock().map(|mut r| r.flush()).unwrap_or_default();
+
     // Finalize WAV
     if let Ok(mut guard) = wav_writer.lock() {
+        if let Some(ref mut writer) = *guard {
+            for sample in flushed {
+                let clamped = sample.max(-1.0).min(1.0);
+                let int_sample = (clamped * 32767.0) as i16;
+                let _ = writer.write_sample(int_sample);
+            }
+        }
         if let Some(writer) = guard.take() {
             writer.finalize()?;
             println!("Recording saved to: {:?}", output_path);
@@ -496,35 +1209,254 @@ fn record_mono_stream(
     Ok(())
 }
 
-/// Record from two audio streams simultaneously into a stereo WAV file
-fn record_dual_streams(
+/// Mono WAV side-file for an extra (beyond mic/system) capture source.
+/// Kept separate from the mic/system stereo file - rather than widening
+/// that file to N channels - so every existing consumer that assumes a
+/// 2-channel dual recording (`whisper::split_stereo_channels`,
+/// `hdf5_store`) keeps working unmodified once a third source is added.
+struct ExtraSourceWriter {
+    writer: WavWriter<BufWriter<File>>,
+}
+
+impl ExtraSourceWriter {
+    fn new(output_path: &Path, sample_rate: u32) -> anyhow::Result<Self> {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let file = File::create(output_path)?;
+        let writer = WavWriter::new(BufWriter::new(file), spec)?;
+        Ok(Self { writer })
+    }
+
+    fn write_samples(&mut self, samples: &[f32]) -> anyhow::Result<()> {
+        for sample in samples {
+            let clamped = sample.max(-1.0).min(1.0);
+            self.writer.write_sample((clamped * 32767.0) as i16)?;
+        }
+        Ok(())
+    }
+
+    fn finalize(self) -> anyhow::Result<()> {
+        self.writer.finalize()?;
+        Ok(())
+    }
+}
+
+/// Path an extra source's side file is written to, next to `output_path`.
+fn extra_source_path(output_path: &Path, label: &str) -> PathBuf {
+    output_path.with_file_name(format!(
+        "{}_{}.wav",
+        output_path.file_stem().and_then(|s| s.to_str()).unwrap_or("output"),
+        label
+    ))
+}
+
+/// Negotiates a device's input config, builds its `AudioSource`, and wires
+/// a cpal input stream that gains, resamples, and pushes timestamped
+/// packets into the source's ring buffer. Shared by every extra source so
+/// `record_multi_streams` doesn't need its own copy of the f32/i16 capture
+/// closures per additional device the way mic/system historically did.
+fn open_extra_source(
+    label: String,
+    device_index: usize,
+    target_sample_rate: u32,
+    active: Arc<AtomicBool>,
+    ring_buffer_capacity: usize,
+    resampler_quality: ResamplerQuality,
+    input_gain: Arc<AtomicU32>,
+    is_recording: Arc<AtomicBool>,
+    capture_start: Instant,
+    app_handle: tauri::AppHandle,
+) -> anyhow::Result<(AudioSource, cpal::Stream)> {
+    let device = get_device_by_index(device_index)?;
+    println!("Recording from {}: {:?}", label, device.name()?);
+
+    let config = device.default_input_config()?;
+    let sample_rate = config.sample_rate().0;
+    let channels = config.channels();
+
+    let source = AudioSource::new(label.clone(), sample_rate, target_sample_rate, Arc::clone(&active), ring_buffer_capacity, resampler_quality)?;
+    let tx = source.sender();
+    let resampler = source.resampler();
+
+    let resampler_f32 = Arc::clone(&resampler);
+    let resampler_i16 = Arc::clone(&resampler);
+    let input_gain_f32 = Arc::clone(&input_gain);
+    let input_gain_i16 = Arc::clone(&input_gain);
+    let is_recording_f32 = Arc::clone(&is_recording);
+    let is_recording_i16 = Arc::clone(&is_recording);
+    let active_err_f32 = Arc::clone(&active);
+    let active_err_i16 = Arc::clone(&active);
+    let tx_f32 = tx.clone();
+    let tx_i16 = tx.clone();
+    let app_handle_f32 = app_handle.clone();
+    let app_handle_i16 = app_handle.clone();
+    let label_err_f32 = label.clone();
+    let label_err_i16 = label.clone();
+
+    let stream = match config.sample_format() {
+        cpal::SampleFormat::F32 => device.build_input_stream(
+            &config.into(),
+            move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                if !is_recording_f32.load(Ordering::SeqCst) {
+                    return;
+                }
+                let mono: Vec<f32> = if channels == 2 {
+                    data.chunks_exact(2).map(|c| (c[0] + c[1]) / 2.0).collect()
+                } else {
+                    data.to_vec()
+                };
+                let gain = levels::load_gain(&input_gain_f32);
+                let mono: Vec<f32> = mono.into_iter().map(|s| s * gain).collect();
+                let resampled = match resampler_f32.lock() {
+                    Ok(mut r) => r.process(&mono),
+                    Err(_) => Vec::new(),
+                };
+                let ts = capture_start.elapsed().as_secs_f64();
+                if let Some(lost) = tx_f32.push(ts, resampled) {
+                    emit_overrun_error(&app_handle_f32, &label_err_f32, lost);
+                }
+            },
+            move |err| {
+                eprintln!("{} stream error: {}", label_err_f32.clone(), err);
+                active_err_f32.store(false, Ordering::SeqCst);
+            },
+            None,
+        )?,
+        cpal::SampleFormat::I16 => device.build_input_stream(
+            &config.into(),
+            move |data: &[i16], _: &cpal::InputCallbackInfo| {
+                if !is_recording_i16.load(Ordering::SeqCst) {
+                    return;
+                }
+                let mono: Vec<f32> = if channels == 2 {
+                    data.chunks_exact(2)
+                        .map(|c| ((c[0] as f32 + c[1] as f32) / 2.0) / 32768.0)
+                        .collect()
+                } else {
+                    data.iter().map(|&s| s as f32 / 32768.0).collect()
+                };
+                let gain = levels::load_gain(&input_gain_i16);
+                let mono: Vec<f32> = mono.into_iter().map(|s| s * gain).collect();
+                let resampled = match resampler_i16.lock() {
+                    Ok(mut r) => r.process(&mono),
+                    Err(_) => Vec::new(),
+                };
+                let ts = capture_start.elapsed().as_secs_f64();
+                if let Some(lost) = tx_i16.push(ts, resampled) {
+                    emit_overrun_error(&app_handle_i16, &label_err_i16, lost);
+                }
+            },
+            move |err| {
+                eprintln!("{} stream error: {}", label_err_i16.clone(), err);
+                active_err_i16.store(false, Ordering::SeqCst);
+            },
+            None,
+        )?,
+        format => return Err(anyhow::anyhow!("Unsupported {} sample format: {:?}", label, format)),
+    };
+
+    Ok((source, stream))
+}
+
+/// Records the mic, an optional system-audio monitor, and any number of
+/// additional sources (a second mic, other participants' loopback devices,
+/// ...) at once. Mic and system are written interleaved into the stereo
+/// `output_path` exactly as before; each entry in `extra_sources` (device
+/// index, activity flag) gets its own ring buffer/timeline via `AudioMixer`
+/// and its own `{stem}_extraN.wav` side file, so a source beyond the
+/// original mic/system pair is actually written instead of being silently
+/// dropped by a `buffer_frames` closure that only recognized two labels.
+fn record_multi_streams(
     output_path: PathBuf,
     target_sample_rate: u32,
     is_recording: Arc<AtomicBool>,
     mic_active: Arc<AtomicBool>,
     system_active: Arc<AtomicBool>,
     mic_device_index: usize,
-    system_device_index: usize,
+    system_device_index: Option<usize>,
+    extra_sources: Vec<(usize, Arc<AtomicBool>)>,
+    input_gain: Arc<AtomicU32>,
+    clip_hold_ms: u64,
+    mic_levels: Arc<ChannelLevel>,
+    system_levels: Arc<ChannelLevel>,
+    mic_muted: Arc<AtomicBool>,
+    system_muted: Arc<AtomicBool>,
+    capture_start: Instant,
+    sync_stats: Arc<std::sync::Mutex<SyncStats>>,
+    io_stats: Arc<std::sync::Mutex<IoStats>>,
+    ring_buffer_capacity: usize,
+    writer_frame_size: usize,
+    app_handle: tauri::AppHandle,
+    resampler_quality: ResamplerQuality,
 ) -> anyhow::Result<()> {
     // Get devices
     let mic_device = get_device_by_index(mic_device_index)?;
-    let system_device = get_device_by_index(system_device_index)?;
-
     println!("Recording from mic: {:?}", mic_device.name()?);
-    println!("Recording from system: {:?}", system_device.name()?);
 
     // Get configs
     let mic_config = mic_device.default_input_config()?;
-    let system_config = system_device.default_input_config()?;
-
     let mic_sample_rate = mic_config.sample_rate().0;
-    let system_sample_rate = system_config.sample_rate().0;
     let mic_channels = mic_config.channels();
-    let system_channels = system_config.channels();
 
-    // Create channels for sample passing
-    let (mic_tx, mic_rx): (Sender<Vec<f32>>, Receiver<Vec<f32>>) = bounded(100);
-    let (system_tx, system_rx): (Sender<Vec<f32>>, Receiver<Vec<f32>>) = bounded(100);
+    // Each source gets its own ring buffer, resampler and timeline, mixed
+    // by a shared `AudioMixer` instead of the writer thread hard-coding a
+    // mic/system pair of channels.
+    let mic_source = AudioSource::new("mic", mic_sample_rate, target_sample_rate, Arc::clone(&mic_active), ring_buffer_capacity, resampler_quality)?;
+    let mic_tx = mic_source.sender();
+    let mic_resampler = mic_source.resampler();
+    let mut sources = vec![mic_source];
+
+    let system_device = match system_device_index {
+        Some(idx) => Some(get_device_by_index(idx)?),
+        None => None,
+    };
+    let system_handle = if let Some(system_device) = &system_device {
+        println!("Recording from system: {:?}", system_device.name()?);
+        let system_config = system_device.default_input_config()?;
+        let system_source = AudioSource::new("system", system_config.sample_rate().0, target_sample_rate, Arc::clone(&system_active), ring_buffer_capacity, resampler_quality)?;
+        let system_tx = system_source.sender();
+        let system_resampler = system_source.resampler();
+        sources.push(system_source);
+        Some((system_config, system_tx, system_resampler))
+    } else {
+        None
+    };
+
+    let mut extra_writer_paths = Vec::with_capacity(extra_sources.len());
+    let mut extra_streams = Vec::with_capacity(extra_sources.len());
+    let mut extra_flush_handles = Vec::with_capacity(extra_sources.len());
+    for (i, (device_index, active)) in extra_sources.into_iter().enumerate() {
+        let label = format!("extra{}", i);
+        let (source, stream) = match open_extra_source(
+            label.clone(),
+            device_index,
+            target_sample_rate,
+            Arc::clone(&active),
+            ring_buffer_capacity,
+            resampler_quality,
+            Arc::clone(&input_gain),
+            Arc::clone(&is_recording),
+            capture_start,
+            app_handle.clone(),
+        ) {
+            Ok(opened) => opened,
+            Err(e) => {
+                eprintln!("Extra audio source {} could not be opened, skipping: {}", device_index, e);
+                active.store(false, Ordering::SeqCst);
+                continue;
+            }
+        };
+        extra_writer_paths.push((label, extra_source_path(&output_path, &source.label)));
+        extra_flush_handles.push((source.sender(), source.resampler()));
+        extra_streams.push(stream);
+        sources.push(source);
+    }
+
+    let mut mixer = AudioMixer::new(sources, writer_frame_size);
 
     // Spawn writer thread
     let is_recording_writer = Arc::clone(&is_recording);
@@ -537,18 +1469,61 @@ fn record_dual_streams(
             }
         };
 
-        while is_recording_writer.load(Ordering::SeqCst) {
-            // Non-blocking receive from both channels
-            let mic_samples = mic_rx.try_recv().ok();
-            let system_samples = system_rx.try_recv().ok();
+        let mut extra_writers: HashMap<String, ExtraSourceWriter> = HashMap::new();
+        for (label, path) in &extra_writer_paths {
+            match ExtraSourceWriter::new(path, target_sample_rate) {
+                Ok(w) => {
+                    extra_writers.insert(label.clone(), w);
+                }
+                Err(e) => eprintln!("Failed to create writer for source '{}': {}", label, e),
+            }
+        }
 
-            if let Some(samples) = mic_samples {
-                stereo_writer.buffer_left(&samples);
+        let mut offset_recorded = false;
+
+        let mut buffer_frames = |mixer: &AudioMixer, frames: Vec<(String, Vec<f32>)>| {
+            for (label, samples) in frames {
+                match label.as_str() {
+                    "mic" => stereo_writer.buffer_left(&samples),
+                    "system" => stereo_writer.buffer_right(&samples),
+                    other => {
+                        if let Some(w) = extra_writers.get_mut(other) {
+                            if let Err(e) = w.write_samples(&samples) {
+                                eprintln!("Error writing source '{}': {}", other, e);
+                            }
+                        } else {
+                            eprintln!("Dropping audio from unrecognized source '{}': no writer configured for it", other);
+                        }
+                    }
+                }
             }
-            if let Some(samples) = system_samples {
-                stereo_writer.buffer_right(&samples);
+
+            // Sync/drift stats only make sense between mic and system (the
+            // two sources sharing the stereo file); extras don't have a
+            // fixed counterpart to measure drift against.
+            let mic_src = mixer.sources().iter().find(|s| s.label == "mic");
+            let system_src = mixer.sources().iter().find(|s| s.label == "system");
+            if let (Some(mic_src), Some(system_src)) = (mic_src, system_src) {
+                let mut stats = sync_stats.lock().unwrap();
+                if !offset_recorded && mic_src.started() && system_src.started() {
+                    stats.offset_ms = ((system_src.first_chunk_secs() - mic_src.first_chunk_secs()) * 1000.0) as i64;
+                    offset_recorded = true;
+                }
+                let max_drift = mic_src.last_drift_secs().abs().max(system_src.last_drift_secs().abs());
+                stats.max_drift_ms = stats.max_drift_ms.max((max_drift * 1000.0) as i64);
             }
 
+            // Ring-buffer health is summed across every source, however many
+            // there are, instead of assuming exactly two.
+            let mut io = io_stats.lock().unwrap();
+            io.overrun_samples = mixer.sources().iter().map(|s| s.overrun_samples()).sum();
+            io.underrun_samples = mixer.sources().iter().map(|s| s.underrun_samples()).sum();
+        };
+
+        while is_recording_writer.load(Ordering::SeqCst) {
+            let frames = mixer.tick();
+            buffer_frames(&mixer, frames);
+
             // Flush what we have
             if let Err(e) = stereo_writer.flush_buffers() {
                 eprintln!("Error writing samples: {}", e);
@@ -558,18 +1533,48 @@ fn record_dual_streams(
             thread::sleep(std::time::Duration::from_millis(10));
         }
 
+        // Once the capture streams are dropped, the recording thread flushes
+        // each source's resampler and sends the drained tail through these
+        // same channels; give that a moment to arrive before finalizing.
+        for _ in 0..50 {
+            let frames = mixer.tick();
+            buffer_frames(&mixer, frames);
+            let _ = stereo_writer.flush_buffers();
+            thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        // Every source's leftover partial frame, shorter than a full mixer
+        // tick, still needs to reach the file.
+        let remainder = mixer.drain_remainder();
+        buffer_frames(&mixer, remainder);
+
         // Final flush
         let _ = stereo_writer.flush_buffers();
         if let Err(e) = stereo_writer.finalize() {
             eprintln!("Error finalizing WAV: {}", e);
         }
+        for (label, writer) in extra_writers {
+            if let Err(e) = writer.finalize() {
+                eprintln!("Error finalizing writer for source '{}': {}", label, e);
+            }
+        }
         println!("Recording saved to: {:?}", output_path);
     });
 
     // Build mic stream
     let is_recording_mic = Arc::clone(&is_recording);
     let mic_active_clone = Arc::clone(&mic_active);
-    let mic_resample_ratio = target_sample_rate as f64 / mic_sample_rate as f64;
+    let mic_resampler_f32 = Arc::clone(&mic_resampler);
+    let mic_resampler_i16 = Arc::clone(&mic_resampler);
+    let mic_tx_flush = mic_tx.clone();
+    let input_gain_mic_f32 = Arc::clone(&input_gain);
+    let mic_levels_f32 = Arc::clone(&mic_levels);
+    let mic_muted_f32 = Arc::clone(&mic_muted);
+    let input_gain_mic_i16 = Arc::clone(&input_gain);
+    let mic_levels_i16 = Arc::clone(&mic_levels);
+    let mic_muted_i16 = Arc::clone(&mic_muted);
+    let app_handle_mic_f32 = app_handle.clone();
+    let app_handle_mic_i16 = app_handle.clone();
 
     let mic_stream = match mic_config.sample_format() {
         cpal::SampleFormat::F32 => {
@@ -587,10 +1592,25 @@ fn record_dual_streams(
                         data.to_vec()
                     };
 
+                    let gain = levels::load_gain(&input_gain_mic_f32);
+                    let (rms, peak) = levels::rms_and_peak(&mono, gain);
+                    mic_levels_f32.update(rms, peak, clip_hold_ms);
+                    let mono: Vec<f32> = if mic_muted_f32.load(Ordering::SeqCst) {
+                        vec![0.0; mono.len()]
+                    } else {
+                        mono.into_iter().map(|s| s * gain).collect()
+                    };
+
                     // Resample
-                    let resampled = resample_linear(&mono, mic_resample_ratio);
+                    let resampled = match mic_resampler_f32.lock() {
+                        Ok(mut r) => r.process(&mono),
+                        Err(_) => Vec::new(),
+                    };
 
-                    let _ = mic_tx.try_send(resampled);
+                    let ts = capture_start.elapsed().as_secs_f64();
+                    if let Some(lost) = mic_tx.push(ts, resampled) {
+                        emit_overrun_error(&app_handle_mic_f32, "mic", lost);
+                    }
                 },
                 move |err| {
                     eprintln!("Mic stream error: {}", err);
@@ -615,8 +1635,23 @@ fn record_dual_streams(
                         data.iter().map(|&s| s as f32 / 32768.0).collect()
                     };
 
-                    let resampled = resample_linear(&mono, mic_resample_ratio);
-                    let _ = mic_tx.try_send(resampled);
+                    let gain = levels::load_gain(&input_gain_mic_i16);
+                    let (rms, peak) = levels::rms_and_peak(&mono, gain);
+                    mic_levels_i16.update(rms, peak, clip_hold_ms);
+                    let mono: Vec<f32> = if mic_muted_i16.load(Ordering::SeqCst) {
+                        vec![0.0; mono.len()]
+                    } else {
+                        mono.into_iter().map(|s| s * gain).collect()
+                    };
+
+                    let resampled = match mic_resampler_i16.lock() {
+                        Ok(mut r) => r.process(&mono),
+                        Err(_) => Vec::new(),
+                    };
+                    let ts = capture_start.elapsed().as_secs_f64();
+                    if let Some(lost) = mic_tx.push(ts, resampled) {
+                        emit_overrun_error(&app_handle_mic_i16, "mic", lost);
+                    }
                 },
                 move |err| {
                     eprintln!("Mic stream error: {}", err);
@@ -628,69 +1663,126 @@ fn record_dual_streams(
         format => return Err(anyhow::anyhow!("Unsupported mic sample format: {:?}", format)),
     };
 
-    // Build system stream
-    let is_recording_system = Arc::clone(&is_recording);
-    let system_active_clone = Arc::clone(&system_active);
-    let system_resample_ratio = target_sample_rate as f64 / system_sample_rate as f64;
-
-    let system_stream = match system_config.sample_format() {
-        cpal::SampleFormat::F32 => {
-            system_device.build_input_stream(
-                &system_config.into(),
-                move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                    if !is_recording_system.load(Ordering::SeqCst) {
-                        return;
-                    }
-
-                    let mono: Vec<f32> = if system_channels == 2 {
-                        data.chunks_exact(2).map(|c| (c[0] + c[1]) / 2.0).collect()
-                    } else {
-                        data.to_vec()
-                    };
+    // Build system stream (only when a system-audio monitor was negotiated)
+    let system_flush: Option<(SourceSender, Arc<std::sync::Mutex<LiveResampler>>)>;
+    let system_stream = if let Some((system_config, system_tx, system_resampler)) = system_handle {
+        let system_device = system_device.as_ref().expect("system_handle implies system_device");
+        let system_channels = system_config.channels();
+
+        let is_recording_system = Arc::clone(&is_recording);
+        let system_active_clone = Arc::clone(&system_active);
+        let system_resampler_f32 = Arc::clone(&system_resampler);
+        let system_resampler_i16 = Arc::clone(&system_resampler);
+        let system_tx_flush = system_tx.clone();
+        let input_gain_system_f32 = Arc::clone(&input_gain);
+        let system_levels_f32 = Arc::clone(&system_levels);
+        let system_muted_f32 = Arc::clone(&system_muted);
+        let input_gain_system_i16 = Arc::clone(&input_gain);
+        let system_levels_i16 = Arc::clone(&system_levels);
+        let system_muted_i16 = Arc::clone(&system_muted);
+        let app_handle_system_f32 = app_handle.clone();
+        let app_handle_system_i16 = app_handle.clone();
+
+        let stream = match system_config.sample_format() {
+            cpal::SampleFormat::F32 => {
+                system_device.build_input_stream(
+                    &system_config.clone().into(),
+                    move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                        if !is_recording_system.load(Ordering::SeqCst) {
+                            return;
+                        }
 
-                    let resampled = resample_linear(&mono, system_resample_ratio);
-                    let _ = system_tx.try_send(resampled);
-                },
-                move |err| {
-                    eprintln!("System stream error: {}", err);
-                    system_active_clone.store(false, Ordering::SeqCst);
-                },
-                None,
-            )?
-        }
-        cpal::SampleFormat::I16 => {
-            system_device.build_input_stream(
-                &system_config.into(),
-                move |data: &[i16], _: &cpal::InputCallbackInfo| {
-                    if !is_recording_system.load(Ordering::SeqCst) {
-                        return;
-                    }
+                        let mono: Vec<f32> = if system_channels == 2 {
+                            data.chunks_exact(2).map(|c| (c[0] + c[1]) / 2.0).collect()
+                        } else {
+                            data.to_vec()
+                        };
+
+                        let gain = levels::load_gain(&input_gain_system_f32);
+                        let (rms, peak) = levels::rms_and_peak(&mono, gain);
+                        system_levels_f32.update(rms, peak, clip_hold_ms);
+                        let mono: Vec<f32> = if system_muted_f32.load(Ordering::SeqCst) {
+                            vec![0.0; mono.len()]
+                        } else {
+                            mono.into_iter().map(|s| s * gain).collect()
+                        };
+
+                        let resampled = match system_resampler_f32.lock() {
+                            Ok(mut r) => r.process(&mono),
+                            Err(_) => Vec::new(),
+                        };
+                        let ts = capture_start.elapsed().as_secs_f64();
+                        if let Some(lost) = system_tx.push(ts, resampled) {
+                            emit_overrun_error(&app_handle_system_f32, "system", lost);
+                        }
+                    },
+                    move |err| {
+                        eprintln!("System stream error: {}", err);
+                        system_active_clone.store(false, Ordering::SeqCst);
+                    },
+                    None,
+                )?
+            }
+            cpal::SampleFormat::I16 => {
+                system_device.build_input_stream(
+                    &system_config.clone().into(),
+                    move |data: &[i16], _: &cpal::InputCallbackInfo| {
+                        if !is_recording_system.load(Ordering::SeqCst) {
+                            return;
+                        }
 
-                    let mono: Vec<f32> = if system_channels == 2 {
-                        data.chunks_exact(2)
-                            .map(|c| ((c[0] as f32 + c[1] as f32) / 2.0) / 32768.0)
-                            .collect()
-                    } else {
-                        data.iter().map(|&s| s as f32 / 32768.0).collect()
-                    };
+                        let mono: Vec<f32> = if system_channels == 2 {
+                            data.chunks_exact(2)
+                                .map(|c| ((c[0] as f32 + c[1] as f32) / 2.0) / 32768.0)
+                                .collect()
+                        } else {
+                            data.iter().map(|&s| s as f32 / 32768.0).collect()
+                        };
+
+                        let gain = levels::load_gain(&input_gain_system_i16);
+                        let (rms, peak) = levels::rms_and_peak(&mono, gain);
+                        system_levels_i16.update(rms, peak, clip_hold_ms);
+                        let mono: Vec<f32> = if system_muted_i16.load(Ordering::SeqCst) {
+                            vec![0.0; mono.len()]
+                        } else {
+                            mono.into_iter().map(|s| s * gain).collect()
+                        };
+
+                        let resampled = match system_resampler_i16.lock() {
+                            Ok(mut r) => r.process(&mono),
+                            Err(_) => Vec::new(),
+                        };
+                        let ts = capture_start.elapsed().as_secs_f64();
+                        if let Some(lost) = system_tx.push(ts, resampled) {
+                            emit_overrun_error(&app_handle_system_i16, "system", lost);
+                        }
+                    },
+                    move |err| {
+                        eprintln!("System stream error: {}", err);
+                        system_active_clone.store(false, Ordering::SeqCst);
+                    },
+                    None,
+                )?
+            }
+            format => return Err(anyhow::anyhow!("Unsupported system sample format: {:?}", format)),
+        };
 
-                    let resampled = resample_linear(&mono, system_resample_ratio);
-                    let _ = system_tx.try_send(resampled);
-                },
-                move |err| {
-                    eprintln!("System stream error: {}", err);
-                    system_active_clone.store(false, Ordering::SeqCst);
-                },
-                None,
-            )?
-        }
-        format => return Err(anyhow::anyhow!("Unsupported system sample format: {:?}", format)),
+        system_flush = Some((system_tx_flush, system_resampler));
+        Some(stream)
+    } else {
+        system_flush = None;
+        None
     };
 
-    // Start both streams
+    // Start every stream
     mic_stream.play()?;
-    system_stream.play()?;
-    println!("Dual recording started...");
+    if let Some(stream) = &system_stream {
+        stream.play()?;
+    }
+    for stream in &extra_streams {
+        stream.play()?;
+    }
+    println!("Multi-source recording started...");
 
     // Keep streams alive while recording
     while is_recording.load(Ordering::SeqCst) {
@@ -700,31 +1792,24 @@ fn record_dual_streams(
     // Stop streams
     drop(mic_stream);
     drop(system_stream);
+    drop(extra_streams);
+
+    // Drain each resampler's group delay and send the tail through the same
+    // channels the writer thread is already draining on shutdown.
+    let flush_ts = capture_start.elapsed().as_secs_f64();
+    let mic_flushed = mic_resampler.lock().map(|mut r| r.flush()).unwrap_or_default();
+    let _ = mic_tx_flush.push(flush_ts, mic_flushed);
+    if let Some((system_tx_flush, system_resampler)) = system_flush {
+        let system_flushed = system_resampler.lock().map(|mut r| r.flush()).unwrap_or_default();
+        let _ = system_tx_flush.push(flush_ts, system_flushed);
+    }
+    for (tx, resampler) in extra_flush_handles {
+        let flushed = resampler.lock().map(|mut r| r.flush()).unwrap_or_default();
+        let _ = tx.push(flush_ts, flushed);
+    }
 
     // Wait for writer to finish
     let _ = writer_handle.join();
 
     Ok(())
 }
-
-/// Simple linear interpolation resampling
-fn resample_linear(samples: &[f32], ratio: f64) -> Vec<f32> {
-    if (ratio - 1.0).abs() < 0.01 {
-        return samples.to_vec();
-    }
-
-    let output_len = (samples.len() as f64 * ratio) as usize;
-    let mut output = Vec::with_capacity(output_len);
-
-    for i in 0..output_len {
-        let src_idx = i as f64 / ratio;
-        let idx0 = src_idx.floor() as usize;
-        let idx1 = (idx0 + 1).min(samples.len().saturating_sub(1));
-        let frac = src_idx - idx0 as f64;
-        let sample = samples.get(idx0).copied().unwrap_or(0.0) * (1.0 - frac as f32)
-            + samples.get(idx1).copied().unwrap_or(0.0) * frac as f32;
-        output.push(sample);
-    }
-
-    output
-}
@@ -0,0 +1,109 @@
+//! Optional structured recording backend.
+//!
+//! The default recording path writes a bare stereo `.wav` keyed only by
+//! `meeting_id`, with the speaker transcript tracked separately as a JSON
+//! blob in sqlite - there's no durable record of which devices, rates, or
+//! resample ratios produced a given file. This module writes the same
+//! session into one self-describing HDF5 file instead: a dataset per
+//! channel holding the raw samples, attributes for the session's identity
+//! and capture config, and the transcript embedded alongside the audio that
+//! produced it, so the whole session travels as a single reproducible unit.
+//!
+//! Gated behind the `hdf5-export` feature since the `hdf5` crate links
+//! against the system HDF5 library - most builds don't need that, so
+//! lightweight WAV output stays the default and this is opt-in.
+#![cfg(feature = "hdf5-export")]
+
+use std::path::Path;
+
+use anyhow::Context;
+use uuid::Uuid;
+
+use crate::dual_audio::SpeakerTranscript;
+
+/// Per-source capture config worth keeping alongside the samples it
+/// produced, so a session can be re-resampled or re-analyzed later without
+/// guessing what the original device was doing.
+pub struct SourceMeta {
+    pub native_sample_rate: u32,
+    /// `target_sample_rate / native_sample_rate`, i.e. the ratio the live
+    /// capture's resampler actually applied to this source.
+    pub resample_ratio: f64,
+}
+
+/// Everything about a dual-audio session that isn't already implied by the
+/// raw samples themselves.
+pub struct SessionMeta {
+    pub mic_device: String,
+    pub system_device: String,
+    pub sample_rate: u32,
+    pub has_dual_audio: bool,
+    pub started_at_unix_ms: u64,
+    pub mic: SourceMeta,
+    pub system: Option<SourceMeta>,
+}
+
+/// Reads back the interleaved stereo WAV `record_multi_streams` already
+/// wrote, splits it into per-channel sample vectors, and writes everything -
+/// samples, device/rate metadata, and the speaker transcript - into one
+/// HDF5 file next to it.
+pub fn write_session(
+    wav_path: &Path,
+    hdf5_path: &Path,
+    meta: &SessionMeta,
+    transcript: &SpeakerTranscript,
+) -> anyhow::Result<()> {
+    let mut reader = hound::WavReader::open(wav_path).context("opening stereo WAV for HDF5 export")?;
+    let samples: Vec<f32> = reader
+        .samples::<i16>()
+        .map(|s| s.map(|v| v as f32 / 32768.0))
+        .collect::<Result<_, _>>()
+        .context("reading stereo WAV samples")?;
+
+    let mic_samples: Vec<f32> = samples.iter().step_by(2).copied().collect();
+
+    let file = hdf5::File::create(hdf5_path).context("creating HDF5 session file")?;
+
+    file.new_dataset_builder()
+        .with_data(&mic_samples)
+        .create("mic")?;
+
+    if meta.has_dual_audio {
+        let system_samples: Vec<f32> = samples.iter().skip(1).step_by(2).copied().collect();
+        file.new_dataset_builder()
+            .with_data(&system_samples)
+            .create("system")?;
+    }
+
+    write_str_attr(&file, "uuid", &Uuid::new_v4().to_string())?;
+    write_str_attr(&file, "mic_device", &meta.mic_device)?;
+    write_str_attr(&file, "system_device", &meta.system_device)?;
+    file.new_attr::<u64>()
+        .create("started_at_unix_ms")?
+        .write_scalar(&meta.started_at_unix_ms)?;
+    file.new_attr::<u32>()
+        .create("sample_rate")?
+        .write_scalar(&meta.sample_rate)?;
+    file.new_attr::<bool>()
+        .create("has_dual_audio")?
+        .write_scalar(&meta.has_dual_audio)?;
+    file.new_attr::<f64>()
+        .create("mic_resample_ratio")?
+        .write_scalar(&meta.mic.resample_ratio)?;
+    if let Some(system) = &meta.system {
+        file.new_attr::<f64>()
+            .create("system_resample_ratio")?
+            .write_scalar(&system.resample_ratio)?;
+    }
+
+    let transcript_json = serde_json::to_string(transcript).context("serializing transcript for HDF5 export")?;
+    write_str_attr(&file, "transcript_json", &transcript_json)?;
+
+    Ok(())
+}
+
+fn write_str_attr(file: &hdf5::File, name: &str, value: &str) -> anyhow::Result<()> {
+    let attr = file.new_attr::<hdf5::types::VarLenUnicode>().create(name)?;
+    attr.write_scalar(&value.parse::<hdf5::types::VarLenUnicode>()?)?;
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::Serialize;
+use tauri::Emitter;
+
+const EMIT_INTERVAL_MS: u64 = 33; // ~30 Hz
+
+/// Lock-free holder for the latest RMS/peak readings of one audio channel.
+///
+/// Updated from the cpal capture callback and read back by a timer task, so
+/// everything here has to be atomics rather than a mutex around the recorder.
+#[derive(Default)]
+pub struct ChannelLevel {
+    rms_bits: AtomicU32,
+    peak_bits: AtomicU32,
+    clip_until_ms: AtomicU64,
+}
+
+impl ChannelLevel {
+    pub fn update(&self, rms: f32, peak: f32, clip_hold_ms: u64) {
+        self.rms_bits.store(rms.to_bits(), Ordering::Relaxed);
+        self.peak_bits.store(peak.to_bits(), Ordering::Relaxed);
+
+        if peak >= 0.99 {
+            self.clip_until_ms.store(now_ms() + clip_hold_ms, Ordering::Relaxed);
+        }
+    }
+
+    pub fn rms(&self) -> f32 {
+        f32::from_bits(self.rms_bits.load(Ordering::Relaxed))
+    }
+
+    pub fn peak(&self) -> f32 {
+        f32::from_bits(self.peak_bits.load(Ordering::Relaxed))
+    }
+
+    pub fn is_clipping(&self) -> bool {
+        now_ms() < self.clip_until_ms.load(Ordering::Relaxed)
+    }
+}
+
+/// Computes RMS and peak (max abs sample) for a buffer after applying `gain`.
+pub fn rms_and_peak(samples: &[f32], gain: f32) -> (f32, f32) {
+    if samples.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    let mut sum_sq = 0.0f32;
+    let mut peak = 0.0f32;
+    for &s in samples {
+        let v = s * gain;
+        sum_sq += v * v;
+        peak = peak.max(v.abs());
+    }
+
+    ((sum_sq / samples.len() as f32).sqrt(), peak)
+}
+
+/// Bit-packs a gain multiplier into an `AtomicU32` so the capture callback can
+/// read it without locking the recorder mutex.
+pub fn gain_atomic(initial: f32) -> AtomicU32 {
+    AtomicU32::new(initial.to_bits())
+}
+
+pub fn load_gain(gain: &AtomicU32) -> f32 {
+    f32::from_bits(gain.load(Ordering::Relaxed))
+}
+
+pub fn store_gain(gain: &AtomicU32, value: f32) {
+    gain.store(value.to_bits(), Ordering::Relaxed);
+}
+
+pub(crate) fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioLevelEvent {
+    pub mic_rms: f32,
+    pub mic_peak: f32,
+    pub system_rms: f32,
+    pub system_peak: f32,
+    pub clipping: bool,
+}
+
+/// Energy-threshold voice-activity parameters used to drive auto-pause/resume.
+#[derive(Debug, Clone, Copy)]
+pub struct VadConfig {
+    pub silence_threshold: f32,
+    pub auto_pause_secs: f32,
+}
+
+/// Spawns a dedicated timer task that reads `mic`/`system` levels at ~30 Hz
+/// and forwards them to the UI as an `audio-level` event, for as long as
+/// `is_recording` stays true. Keeps the capture callback free of any Tauri
+/// or locking concerns.
+///
+/// When `vad` is set, the same loop watches the mic channel's RMS and emits
+/// `auto-pause` once it has stayed below `silence_threshold` for
+/// `auto_pause_secs`, then `auto-resume` once it climbs back above 1.5x the
+/// threshold (hysteresis, to avoid chattering around the floor).
+pub fn spawn_level_emitter(
+    app_handle: tauri::AppHandle,
+    mic: Arc<ChannelLevel>,
+    system: Option<Arc<ChannelLevel>>,
+    is_recording: Arc<AtomicBool>,
+    vad: Option<VadConfig>,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut silent_ms: f32 = 0.0;
+        let mut auto_paused = false;
+
+        while is_recording.load(Ordering::SeqCst) {
+            let (system_rms, system_peak, system_clipping) = match &system {
+                Some(level) => (level.rms(), level.peak(), level.is_clipping()),
+                None => (0.0, 0.0, false),
+            };
+
+            let event = AudioLevelEvent {
+                mic_rms: mic.rms(),
+                mic_peak: mic.peak(),
+                system_rms,
+                system_peak,
+                clipping: mic.is_clipping() || system_clipping,
+            };
+
+            let _ = app_handle.emit("audio-level", event);
+
+            if let Some(cfg) = vad {
+                let mic_rms = mic.rms();
+                if mic_rms < cfg.silence_threshold {
+                    silent_ms += EMIT_INTERVAL_MS as f32;
+                    if !auto_paused && silent_ms >= cfg.auto_pause_secs * 1000.0 {
+                        auto_paused = true;
+                        let _ = app_handle.emit("auto-pause", ());
+                    }
+                } else {
+                    silent_ms = 0.0;
+                    if auto_paused && mic_rms > cfg.silence_threshold * 1.5 {
+                        auto_paused = false;
+                        let _ = app_handle.emit("auto-resume", ());
+                    }
+                }
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(EMIT_INTERVAL_MS)).await;
+        }
+    });
+}
@@ -4,20 +4,26 @@ pub mod whisper;
 pub mod ai;
 pub mod config;
 pub mod dual_audio;
+pub mod levels;
+pub mod mixer;
+pub mod vad;
+pub mod control;
+pub mod credentials;
+#[cfg(feature = "hdf5-export")]
+pub mod hdf5_store;
 
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use tauri::Manager;
+use tauri::{Emitter, Manager};
 
-use dual_audio::{DualAudioRecorder, DualRecordingStatus, DualRecordingResult, SpeakerTranscript, SpeakerSegment};
+use dual_audio::{DualRecordingStatus, DualRecordingResult, SpeakerTranscript, SpeakerSegment};
 
 // Application state
 pub struct AppState {
     pub db: Arc<Mutex<Option<db::Database>>>,
-    pub audio: Arc<Mutex<audio::AudioRecorder>>,
-    pub dual_audio: Arc<Mutex<DualAudioRecorder>>,
-    pub whisper: Arc<Mutex<whisper::WhisperTranscriber>>,
+    pub audio_control: control::AudioActorHandle,
+    pub whisper: Arc<whisper::WhisperTranscriber>,
     pub ai: Arc<Mutex<ai::AIClient>>,
     pub config: Arc<Mutex<config::AppConfig>>,
 }
@@ -55,79 +61,64 @@ async fn delete_meeting(state: tauri::State<'_, AppState>, id: String) -> Result
 // ===== Audio Commands =====
 
 #[tauri::command]
-async fn start_recording(state: tauri::State<'_, AppState>, meeting_id: String) -> Result<String, String> {
-    let mut audio = state.audio.lock().await;
-    audio.start_recording(&meeting_id).await.map_err(|e| e.to_string())
+async fn start_recording(
+    state: tauri::State<'_, AppState>,
+    app: tauri::AppHandle,
+    meeting_id: String,
+) -> Result<String, String> {
+    state.audio_control.start_recording(meeting_id, app).await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn stop_recording(state: tauri::State<'_, AppState>) -> Result<(String, u64), String> {
-    let (meeting_id, duration) = {
-        let mut audio = state.audio.lock().await;
-        audio.stop_recording().await.map_err(|e| e.to_string())?
-    };
+async fn stop_recording(state: tauri::State<'_, AppState>) -> Result<audio::RecordingOutcome, String> {
+    let outcome = state.audio_control.stop_recording().await.map_err(|e| e.to_string())?;
 
-    // Get the audio path
-    let app_data_dir = get_app_data_dir();
-    let audio_path = app_data_dir.join("audio").join(format!("{}.wav", meeting_id));
-    let audio_path_str = audio_path.to_string_lossy().to_string();
-
-    // Update meeting with audio path and duration
     let db = state.db.lock().await;
     if let Some(db) = db.as_ref() {
-        if let Err(e) = db.update_meeting_audio(&meeting_id, &audio_path_str, duration as i64).await {
-            eprintln!("Failed to update meeting audio: {}", e);
+        match &outcome {
+            audio::RecordingOutcome::Recorded { meeting_id, duration_secs } => {
+                let app_data_dir = get_app_data_dir();
+                let audio_path = app_data_dir.join("audio").join(format!("{}.wav", meeting_id));
+                let audio_path_str = audio_path.to_string_lossy().to_string();
+                if let Err(e) = db.update_meeting_audio(meeting_id, &audio_path_str, *duration_secs as i64).await {
+                    eprintln!("Failed to update meeting audio: {}", e);
+                }
+            }
+            audio::RecordingOutcome::NoAudioCaptured { meeting_id } => {
+                if let Err(e) = db.clear_meeting_audio(meeting_id).await {
+                    eprintln!("Failed to clear meeting audio: {}", e);
+                }
+            }
         }
     }
 
-    Ok((meeting_id, duration))
+    Ok(outcome)
 }
 
 #[tauri::command]
 async fn pause_recording(state: tauri::State<'_, AppState>) -> Result<(), String> {
-    let mut audio = state.audio.lock().await;
-    audio.pause_recording().await.map_err(|e| e.to_string())
+    state.audio_control.pause_recording().await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 async fn resume_recording(state: tauri::State<'_, AppState>) -> Result<(), String> {
-    let mut audio = state.audio.lock().await;
-    audio.resume_recording().await.map_err(|e| e.to_string())
+    state.audio_control.resume_recording().await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
 async fn get_recording_state(state: tauri::State<'_, AppState>) -> Result<serde_json::Value, String> {
-    // Check dual audio state first
-    let dual_audio = state.dual_audio.lock().await;
-    let dual_status = dual_audio.get_status();
-
-    // If dual audio is recording, return that state
-    if dual_status.get("is_recording").and_then(|v| v.as_bool()).unwrap_or(false) {
-        return Ok(serde_json::json!({
-            "state": "recording",
-            "meeting_id": dual_status.get("meeting_id"),
-            "is_dual_mode": true,
-            "mic_active": dual_status.get("mic_active"),
-            "system_active": dual_status.get("system_active"),
-            "mic_device": dual_status.get("mic_device"),
-            "system_device": dual_status.get("system_device"),
-        }));
-    }
-    drop(dual_audio);
-
-    // Fall back to legacy single audio state
-    let audio = state.audio.lock().await;
-    let legacy_state = audio.get_state();
-    let state_str = legacy_state.get("state").and_then(|v| v.as_str()).unwrap_or("idle");
+    let status = state.audio_control.get_status().await.map_err(|e| e.to_string())?;
 
     Ok(serde_json::json!({
-        "state": state_str,
-        "meeting_id": legacy_state.get("meeting_id"),
-        "is_dual_mode": false,
-        "mic_active": state_str == "recording",
-        "system_active": false,
-        "mic_device": null,
-        "system_device": null,
+        "state": status.state,
+        "meeting_id": status.meeting_id,
+        "is_dual_mode": status.is_dual_mode,
+        "mic_active": status.mic_active,
+        "system_active": status.system_active,
+        "mic_device": status.mic_device,
+        "system_device": status.system_device,
+        "pause_markers": status.pause_markers,
+        "paused_duration_secs": status.paused_duration_secs,
     }))
 }
 
@@ -141,8 +132,7 @@ async fn set_recording_device(
     state: tauri::State<'_, AppState>,
     device_index: Option<usize>,
 ) -> Result<(), String> {
-    let mut audio = state.audio.lock().await;
-    audio.set_device(device_index);
+    state.audio_control.set_device(device_index).await.map_err(|e| e.to_string())?;
 
     // Persist to config
     let mut config = state.config.lock().await;
@@ -154,8 +144,65 @@ async fn set_recording_device(
 
 #[tauri::command]
 async fn get_selected_audio_device(state: tauri::State<'_, AppState>) -> Result<Option<usize>, String> {
-    let audio = state.audio.lock().await;
-    Ok(audio.get_selected_device())
+    state.audio_control.get_selected_device().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn set_loopback_device(
+    state: tauri::State<'_, AppState>,
+    device_index: Option<usize>,
+) -> Result<(), String> {
+    state.audio_control.set_loopback_device(device_index).await.map_err(|e| e.to_string())?;
+
+    let mut config = state.config.lock().await;
+    config.loopback_device_index = device_index;
+    config.save().await.map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_resampler_quality(
+    state: tauri::State<'_, AppState>,
+    quality: audio::ResamplerQuality,
+) -> Result<(), String> {
+    state.audio_control.set_resampler_quality(quality).await.map_err(|e| e.to_string())?;
+
+    let mut config = state.config.lock().await;
+    config.resampler_quality = quality;
+    config.save().await.map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_input_gain(state: tauri::State<'_, AppState>, gain: f32) -> Result<(), String> {
+    state.audio_control.set_input_gain(gain).await.map_err(|e| e.to_string())?;
+
+    let mut config = state.config.lock().await;
+    config.input_gain = gain;
+    config.save().await.map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_vad_config(
+    state: tauri::State<'_, AppState>,
+    silence_threshold: f32,
+    auto_pause_secs: f32,
+    trim_silence: bool,
+) -> Result<(), String> {
+    state.audio_control.set_vad_thresholds(silence_threshold, auto_pause_secs).await.map_err(|e| e.to_string())?;
+    state.whisper.set_silence_trimming(trim_silence);
+
+    let mut config = state.config.lock().await;
+    config.silence_threshold = silence_threshold;
+    config.auto_pause_secs = auto_pause_secs;
+    config.trim_silence = trim_silence;
+    config.save().await.map_err(|e| e.to_string())?;
+
+    Ok(())
 }
 
 // ===== Dual Audio Commands =====
@@ -163,15 +210,18 @@ async fn get_selected_audio_device(state: tauri::State<'_, AppState>) -> Result<
 #[tauri::command]
 async fn start_dual_recording(
     state: tauri::State<'_, AppState>,
+    app: tauri::AppHandle,
     meeting_id: String,
 ) -> Result<DualRecordingStatus, String> {
     let config = state.config.lock().await;
     let mic_device_index = config.mic_device_index;
     let system_device_index = config.system_device_index;
+    let extra_device_indices = config.extra_device_indices.clone();
     drop(config);
 
-    let mut dual_audio = state.dual_audio.lock().await;
-    dual_audio.start(&meeting_id, mic_device_index, system_device_index)
+    state.audio_control
+        .start_dual(meeting_id, mic_device_index, system_device_index, extra_device_indices, app)
+        .await
         .map_err(|e| e.to_string())
 }
 
@@ -179,10 +229,7 @@ async fn start_dual_recording(
 async fn stop_dual_recording(
     state: tauri::State<'_, AppState>,
 ) -> Result<DualRecordingResult, String> {
-    let result = {
-        let mut dual_audio = state.dual_audio.lock().await;
-        dual_audio.stop().await.map_err(|e| e.to_string())?
-    };
+    let result = state.audio_control.stop_dual().await.map_err(|e| e.to_string())?;
 
     // Get the audio path
     let app_data_dir = get_app_data_dir();
@@ -208,6 +255,7 @@ async fn get_dual_audio_config(
     Ok(serde_json::json!({
         "mic_device_index": config.mic_device_index,
         "system_device_index": config.system_device_index,
+        "extra_device_indices": config.extra_device_indices,
     }))
 }
 
@@ -216,6 +264,7 @@ async fn set_dual_audio_config(
     state: tauri::State<'_, AppState>,
     mic_device_index: Option<usize>,
     system_device_index: Option<usize>,
+    extra_device_indices: Option<Vec<usize>>,
 ) -> Result<(), String> {
     // Validate devices if provided
     let devices = audio::list_audio_devices().map_err(|e| e.to_string())?;
@@ -236,10 +285,21 @@ async fn set_dual_audio_config(
         }
     }
 
+    if let Some(extra_indices) = &extra_device_indices {
+        for &idx in extra_indices {
+            if idx >= devices.len() {
+                return Err("Invalid extra device index".to_string());
+            }
+        }
+    }
+
     // Update config
     let mut config = state.config.lock().await;
     config.mic_device_index = mic_device_index;
     config.system_device_index = system_device_index;
+    if let Some(extra_indices) = extra_device_indices {
+        config.extra_device_indices = extra_indices;
+    }
     config.save().await.map_err(|e| e.to_string())?;
 
     Ok(())
@@ -260,9 +320,15 @@ async fn get_audio_devices_by_type(
     Ok(filtered)
 }
 
+#[tauri::command]
+async fn probe_audio_device(index: usize) -> Result<dual_audio::DeviceCapabilities, String> {
+    dual_audio::probe_device(index).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 async fn transcribe_dual_audio(
     state: tauri::State<'_, AppState>,
+    app: tauri::AppHandle,
     meeting_id: String,
     audio_path: String,
 ) -> Result<SpeakerTranscript, String> {
@@ -280,16 +346,31 @@ async fn transcribe_dual_audio(
     let (left_path, right_path) = whisper::split_stereo_channels(&audio_path)
         .map_err(|e| format!("Failed to split channels: {}", e))?;
 
-    let whisper = state.whisper.lock().await;
+    // Transcribe both channels concurrently (gated by max_parallel_transcriptions),
+    // each reporting its own "transcribe-progress" events. Join both results
+    // before touching left_path/right_path so cleanup always runs, even if
+    // one channel failed.
+    let left_whisper = Arc::clone(&state.whisper);
+    let left_app = app.clone();
+    let left_path_str = left_path.to_string_lossy().to_string();
+    let left_task = async move {
+        left_whisper.transcribe_with_progress(&left_path_str, "Me", left_app).await
+    };
 
-    // Transcribe both channels (sequentially for now, could be parallelized)
-    let left_transcript = whisper.transcribe(left_path.to_string_lossy().as_ref())
-        .await
-        .map_err(|e| format!("Failed to transcribe mic channel: {}", e))?;
+    let right_whisper = Arc::clone(&state.whisper);
+    let right_app = app.clone();
+    let right_path_str = right_path.to_string_lossy().to_string();
+    let right_task = async move {
+        right_whisper.transcribe_with_progress(&right_path_str, "Them", right_app).await
+    };
 
-    let right_transcript = whisper.transcribe(right_path.to_string_lossy().as_ref())
-        .await
-        .map_err(|e| format!("Failed to transcribe system channel: {}", e))?;
+    let transcribe_result = tokio::try_join!(left_task, right_task);
+
+    let _ = std::fs::remove_file(&left_path);
+    let _ = std::fs::remove_file(&right_path);
+
+    let (left_transcript, right_transcript) = transcribe_result
+        .map_err(|e| format!("Failed to transcribe dual audio: {}", e))?;
 
     // Parse transcripts into segments
     let mic_segments: Vec<SpeakerSegment> = whisper::parse_transcript_to_segments(&left_transcript)
@@ -329,7 +410,7 @@ async fn transcribe_dual_audio(
         .unwrap_or_else(|| "System Audio".to_string());
 
     // Merge segments with overlap detection
-    let merged_segments = SpeakerTranscript::merge(mic_segments, system_segments);
+    let merged_segments = SpeakerTranscript::merge(vec![mic_segments, system_segments]);
 
     let transcript = SpeakerTranscript {
         version: 1,
@@ -346,21 +427,83 @@ async fn transcribe_dual_audio(
     let db = state.db.lock().await;
     if let Some(db) = db.as_ref() {
         let _ = db.update_meeting_transcript(&meeting_id, &transcript_json).await;
-    }
 
-    // Cleanup temp files
-    let _ = std::fs::remove_file(&left_path);
-    let _ = std::fs::remove_file(&right_path);
+        #[cfg(feature = "hdf5-export")]
+        {
+            if let Ok(Some(meeting)) = db.get_meeting(&meeting_id).await {
+                if let Err(e) = export_session_hdf5(&audio_path, &meeting_id, meeting.created_at, &transcript, config.mic_device_index, config.system_device_index) {
+                    eprintln!("Failed to write HDF5 session export: {}", e);
+                }
+            }
+        }
+    }
 
     Ok(transcript)
 }
 
+/// Best-effort HDF5 export alongside the default WAV/sqlite path - logged,
+/// never fatal to the transcription request that triggered it.
+#[cfg(feature = "hdf5-export")]
+fn export_session_hdf5(
+    audio_path: &str,
+    meeting_id: &str,
+    started_at: chrono::DateTime<chrono::Utc>,
+    transcript: &SpeakerTranscript,
+    mic_device_index: Option<usize>,
+    system_device_index: Option<usize>,
+) -> anyhow::Result<()> {
+    const DUAL_AUDIO_TARGET_SAMPLE_RATE: u32 = 16000;
+
+    let source_meta = |index: Option<usize>| -> hdf5_store::SourceMeta {
+        let native_sample_rate = index
+            .and_then(|idx| dual_audio::probe_device(idx).ok())
+            .map(|caps| caps.default_sample_rate)
+            .unwrap_or(DUAL_AUDIO_TARGET_SAMPLE_RATE);
+        hdf5_store::SourceMeta {
+            native_sample_rate,
+            resample_ratio: DUAL_AUDIO_TARGET_SAMPLE_RATE as f64 / native_sample_rate as f64,
+        }
+    };
+
+    let meta = hdf5_store::SessionMeta {
+        mic_device: transcript.mic_device.clone(),
+        system_device: transcript.system_device.clone(),
+        sample_rate: DUAL_AUDIO_TARGET_SAMPLE_RATE,
+        has_dual_audio: transcript.has_dual_audio,
+        started_at_unix_ms: started_at.timestamp_millis().max(0) as u64,
+        mic: source_meta(mic_device_index),
+        system: transcript.has_dual_audio.then(|| source_meta(system_device_index)),
+    };
+
+    let hdf5_path = std::path::Path::new(audio_path).with_extension("h5");
+    hdf5_store::write_session(std::path::Path::new(audio_path), &hdf5_path, &meta, transcript)
+}
+
 #[tauri::command]
 async fn get_dual_recording_state(
     state: tauri::State<'_, AppState>,
 ) -> Result<serde_json::Value, String> {
-    let dual_audio = state.dual_audio.lock().await;
-    Ok(dual_audio.get_status())
+    let status = state.audio_control.get_status().await.map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({
+        "is_recording": status.is_dual_mode && status.state == "recording",
+        "meeting_id": status.meeting_id,
+        "mic_active": status.mic_active,
+        "system_active": status.system_active,
+        "mic_device": status.mic_device,
+        "system_device": status.system_device,
+        "mic_muted": status.mic_muted,
+        "system_muted": status.system_muted,
+    }))
+}
+
+#[tauri::command]
+async fn mute_mic(state: tauri::State<'_, AppState>, muted: bool) -> Result<(), String> {
+    state.audio_control.mute_mic(muted).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn mute_system(state: tauri::State<'_, AppState>, muted: bool) -> Result<(), String> {
+    state.audio_control.mute_system(muted).await.map_err(|e| e.to_string())
 }
 
 // ===== Transcription Commands =====
@@ -371,8 +514,7 @@ async fn transcribe_audio(
     meeting_id: String,
     audio_path: String,
 ) -> Result<String, String> {
-    let whisper = state.whisper.lock().await;
-    let transcript = whisper.transcribe(&audio_path).await.map_err(|e| e.to_string())?;
+    let transcript = state.whisper.transcribe(&audio_path).await.map_err(|e| e.to_string())?;
 
     // Update meeting with transcript
     let db = state.db.lock().await;
@@ -385,14 +527,26 @@ async fn transcribe_audio(
 
 #[tauri::command]
 async fn is_whisper_model_available(state: tauri::State<'_, AppState>) -> Result<bool, String> {
-    let whisper = state.whisper.lock().await;
-    Ok(whisper.is_model_available())
+    Ok(state.whisper.is_model_available())
 }
 
 #[tauri::command]
 async fn get_whisper_model_path(state: tauri::State<'_, AppState>) -> Result<String, String> {
-    let whisper = state.whisper.lock().await;
-    Ok(whisper.get_model_path().to_string())
+    Ok(state.whisper.get_model_path().to_string())
+}
+
+#[tauri::command]
+async fn export_transcript(
+    state: tauri::State<'_, AppState>,
+    audio_path: String,
+    output_path: String,
+    format: whisper::OutputFormat,
+) -> Result<(), String> {
+    state
+        .whisper
+        .transcribe_to(&audio_path, &output_path, format)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 // ===== AI Commands =====
@@ -418,7 +572,7 @@ async fn set_ai_api_key(
 async fn get_ai_config(state: tauri::State<'_, AppState>) -> Result<serde_json::Value, String> {
     let config = state.config.lock().await;
     Ok(serde_json::json!({
-        "has_api_key": config.ai_api_key.is_some(),
+        "has_api_key": config.ai_key_id.is_some(),
         "provider": config.ai_provider.clone().unwrap_or_else(|| "openai".to_string())
     }))
 }
@@ -481,6 +635,15 @@ async fn update_note(
     db.update_note(&meeting_id, &content, timestamps).await.map_err(|e| e.to_string())
 }
 
+// ===== Search Commands =====
+
+#[tauri::command]
+async fn search_meetings(state: tauri::State<'_, AppState>, query: String) -> Result<Vec<db::SearchHit>, String> {
+    let db = state.db.lock().await;
+    let db = db.as_ref().ok_or("Database not initialized")?;
+    db.search(&query).await.map_err(|e| e.to_string())
+}
+
 // ===== Template Commands =====
 
 #[tauri::command]
@@ -522,14 +685,19 @@ pub fn run() {
             let db_path = app_data_dir.join("wizscribe.db");
             let audio_dir = app_data_dir.join("audio");
 
+            // The audio-control task owns both recorders and broadcasts the
+            // status after each command it handles; the forwarder below turns
+            // those into Tauri events so the frontend doesn't have to poll
+            // `get_recording_state` for command-driven transitions.
+            let (audio_control, status_tx) = control::spawn(audio_dir);
+
             // Initialize state
             let state = AppState {
                 db: Arc::new(Mutex::new(None)),
-                audio: Arc::new(Mutex::new(audio::AudioRecorder::new(audio_dir.clone()))),
-                dual_audio: Arc::new(Mutex::new(dual_audio::DualAudioRecorder::new(audio_dir))),
-                whisper: Arc::new(Mutex::new(
+                audio_control,
+                whisper: Arc::new(
                     whisper::WhisperTranscriber::new().expect("Failed to create WhisperTranscriber")
-                )),
+                ),
                 ai: Arc::new(Mutex::new(
                     ai::AIClient::new().expect("Failed to create AIClient")
                 )),
@@ -538,6 +706,15 @@ pub fn run() {
 
             app.manage(state);
 
+            // Forward audio-control status transitions to the frontend as events.
+            let forwarder_handle = app.handle().clone();
+            let mut status_rx = status_tx.subscribe();
+            tauri::async_runtime::spawn(async move {
+                while let Ok(msg) = status_rx.recv().await {
+                    let _ = forwarder_handle.emit("recording-status", msg);
+                }
+            });
+
             // Initialize database and load config asynchronously
             let app_handle = app.handle().clone();
             let db_path_str = format!("sqlite:{}?mode=rwc", db_path.display());
@@ -554,26 +731,80 @@ pub fn run() {
                     *cfg = loaded_config.clone();
                 }
 
-                // If we have saved API credentials, set them in the AI client
-                if let (Some(api_key), Some(provider)) = (&loaded_config.ai_api_key, &loaded_config.ai_provider) {
-                    let ai = state.ai.lock().await;
-                    if let Err(e) = ai.set_api_key(api_key, provider).await {
-                        eprintln!("Failed to restore API key: {}", e);
-                    } else {
-                        println!("API key restored from config");
+                // If we have a saved API credential reference, fetch the actual
+                // key from the system keyring and set it in the AI client
+                if let (Some(key_id), Some(provider)) = (&loaded_config.ai_key_id, &loaded_config.ai_provider) {
+                    match credentials::load_api_key(provider, key_id) {
+                        Ok(Some(api_key)) => {
+                            let ai = state.ai.lock().await;
+                            if let Err(e) = ai.set_api_key(&api_key, provider).await {
+                                eprintln!("Failed to restore API key: {}", e);
+                            } else {
+                                println!("API key restored from system keyring");
+                            }
+                        }
+                        Ok(None) => {
+                            eprintln!("No API key found in system keyring for saved reference");
+                        }
+                        Err(e) => eprintln!("Failed to read API key from system keyring: {}", e),
                     }
                 }
 
                 // Restore audio device selection
                 if let Some(device_index) = loaded_config.selected_audio_device {
-                    let mut audio = state.audio.lock().await;
-                    audio.set_device(Some(device_index));
-                    println!("Audio device restored from config: index {}", device_index);
+                    if let Err(e) = state.audio_control.set_device(Some(device_index)).await {
+                        eprintln!("Failed to restore audio device: {}", e);
+                    } else {
+                        println!("Audio device restored from config: index {}", device_index);
+                    }
+                }
+
+                // Restore loopback device selection
+                if let Some(device_index) = loaded_config.loopback_device_index {
+                    if let Err(e) = state.audio_control.set_loopback_device(Some(device_index)).await {
+                        eprintln!("Failed to restore loopback device: {}", e);
+                    } else {
+                        println!("Loopback device restored from config: index {}", device_index);
+                    }
+                }
+
+                // Restore resampler quality
+                if let Err(e) = state.audio_control.set_resampler_quality(loaded_config.resampler_quality).await {
+                    eprintln!("Failed to restore resampler quality: {}", e);
+                }
+
+                // Restore input gain and clip-hold duration
+                if let Err(e) = state.audio_control.set_input_gain(loaded_config.input_gain).await {
+                    eprintln!("Failed to restore input gain: {}", e);
+                }
+                if let Err(e) = state.audio_control.set_clip_hold_ms(loaded_config.clip_hold_ms).await {
+                    eprintln!("Failed to restore clip-hold duration: {}", e);
                 }
 
+                // Restore voice-activity thresholds
+                if let Err(e) = state.audio_control
+                    .set_vad_thresholds(loaded_config.silence_threshold, loaded_config.auto_pause_secs)
+                    .await
+                {
+                    eprintln!("Failed to restore VAD thresholds: {}", e);
+                }
+                state.whisper.set_silence_trimming(loaded_config.trim_silence);
+                state.whisper.set_max_parallel_transcriptions(loaded_config.max_parallel_transcriptions).await;
+
                 // Initialize database
                 match db::Database::new(&db_path_str).await {
                     Ok(database) => {
+                        // Forward change events to the frontend as they're published,
+                        // so it can refresh a meeting/note view instead of polling
+                        // list_meetings after a background transcription/summary finishes.
+                        let mut db_events = database.subscribe();
+                        let db_events_handle = app_handle.clone();
+                        tauri::async_runtime::spawn(async move {
+                            while let Ok(event) = db_events.recv().await {
+                                let _ = db_events_handle.emit("db-changed", event);
+                            }
+                        });
+
                         let mut db = state.db.lock().await;
                         *db = Some(database);
                         println!("Database initialized successfully");
@@ -601,18 +832,26 @@ pub fn run() {
             list_audio_devices,
             set_recording_device,
             get_selected_audio_device,
+            set_loopback_device,
+            set_resampler_quality,
+            set_input_gain,
+            set_vad_config,
             // Dual audio commands
             start_dual_recording,
             stop_dual_recording,
             get_dual_audio_config,
             set_dual_audio_config,
             get_audio_devices_by_type,
+            probe_audio_device,
             transcribe_dual_audio,
             get_dual_recording_state,
+            mute_mic,
+            mute_system,
             // Transcription commands
             transcribe_audio,
             is_whisper_model_available,
             get_whisper_model_path,
+            export_transcript,
             // AI commands
             set_ai_api_key,
             get_ai_config,
@@ -622,6 +861,8 @@ pub fn run() {
             // Note commands
             get_note,
             update_note,
+            // Search commands
+            search_meetings,
             // Template commands
             list_templates,
             get_template,
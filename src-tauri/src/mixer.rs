@@ -0,0 +1,109 @@
+//! Channel-mixing for the dual-audio capture path. `record_multi_streams`
+//! (see `dual_audio`) writes mic and system audio as separate stereo
+//! channels; this module is the mixing-matrix layer that
+//! `DualAudioRecorder::stop` uses to produce a single analysis-ready track
+//! from them, the way a proper sound-conversion library would.
+
+use std::collections::HashMap;
+
+/// A channel `mix` knows how to place: a mono mic input, and the left/right
+/// pair of a stereo system-audio (loopback) capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceChannel {
+    Mic,
+    SystemLeft,
+    SystemRight,
+}
+
+/// Output layout `mix` can target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputLayout {
+    Mono,
+    Stereo,
+}
+
+/// One named input to `mix`: a source's audio tagged with which channel it
+/// represents. The source layout `mix` reads from is simply whichever
+/// channels are present here - there's no separate layout tag to keep in
+/// sync with the buffers actually passed in.
+#[derive(Debug, Clone)]
+pub struct ChannelBuffer {
+    pub channel: SourceChannel,
+    pub samples: Vec<f32>,
+}
+
+impl ChannelBuffer {
+    pub fn new(channel: SourceChannel, samples: Vec<f32>) -> Self {
+        Self { channel, samples }
+    }
+}
+
+/// Per-source gain applied before summing, keyed by `SourceChannel`. Lets a
+/// caller duck system audio under speech (e.g. hold system at 0.4 while mic
+/// stays at 1.0) instead of `mix` always treating every source as equally
+/// loud. A channel absent from the table gets unity gain.
+pub type GainTable = HashMap<SourceChannel, f32>;
+
+fn gain_for(gains: &GainTable, channel: SourceChannel) -> f32 {
+    gains.get(&channel).copied().unwrap_or(1.0)
+}
+
+/// Soft-limits a summed sample into `[-1, 1]` with a smooth knee instead of
+/// hard-clamping, so summing two full-scale sources rounds off the peak
+/// into a gentle saturation rather than a harsh clipped edge.
+fn soft_limit(sample: f32) -> f32 {
+    sample.tanh()
+}
+
+fn sample_at(streams: &[ChannelBuffer], gains: &GainTable, channel: SourceChannel, i: usize) -> Option<f32> {
+    streams
+        .iter()
+        .find(|s| s.channel == channel)
+        .map(|s| s.samples.get(i).copied().unwrap_or(0.0) * gain_for(gains, channel))
+}
+
+/// Mixes `streams` down to `dst_layout`, applying `gains` per source before
+/// summing. All buffers are assumed to already be at the same sample rate;
+/// a source shorter than the longest one is treated as silence past its end
+/// rather than truncating the mix to the shortest source.
+///
+/// Mono averages every channel present. Stereo pans the mic to center
+/// (split evenly across both output channels) and preserves the system L/R
+/// pair as-is; a mono-only input (no system channels) comes out centered on
+/// both sides too.
+pub fn mix(streams: &[ChannelBuffer], dst_layout: OutputLayout, gains: &GainTable) -> Vec<f32> {
+    let len = streams.iter().map(|s| s.samples.len()).max().unwrap_or(0);
+    if len == 0 {
+        return Vec::new();
+    }
+
+    match dst_layout {
+        OutputLayout::Mono => {
+            let channels = [SourceChannel::Mic, SourceChannel::SystemLeft, SourceChannel::SystemRight];
+            (0..len)
+                .map(|i| {
+                    let mut sum = 0.0f32;
+                    let mut count = 0usize;
+                    for &channel in &channels {
+                        if let Some(s) = sample_at(streams, gains, channel, i) {
+                            sum += s;
+                            count += 1;
+                        }
+                    }
+                    if count == 0 { 0.0 } else { soft_limit(sum / count as f32) }
+                })
+                .collect()
+        }
+        OutputLayout::Stereo => {
+            let mut output = Vec::with_capacity(len * 2);
+            for i in 0..len {
+                let mic = sample_at(streams, gains, SourceChannel::Mic, i).unwrap_or(0.0) * 0.5;
+                let sys_l = sample_at(streams, gains, SourceChannel::SystemLeft, i).unwrap_or(0.0);
+                let sys_r = sample_at(streams, gains, SourceChannel::SystemRight, i).unwrap_or(0.0);
+                output.push(soft_limit(mic + sys_l));
+                output.push(soft_limit(mic + sys_r));
+            }
+            output
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Offline, FFT-based voice-activity detection over a recorded WAV file -
+//! distinct from the live per-buffer RMS threshold in `levels` that drives
+//! auto-pause. Used to trim dead air before transcription; the speech
+//! segments themselves are returned in seconds, the unit `Note.timestamps`
+//! uses, though nothing in this crate writes them into a note.
+
+use std::path::PathBuf;
+use realfft::RealFftPlanner;
+use realfft::num_complex::Complex32;
+
+/// Analysis frame size, ~32 ms at 16 kHz.
+const FRAME_SIZE: usize = 512;
+/// 50% overlap between consecutive frames.
+const FRAME_STEP: usize = FRAME_SIZE / 2;
+/// Band energy is summed over this range - where speech formants live -
+/// rather than the full spectrum, so broadband noise outside it doesn't
+/// raise the noise floor's sensitivity to real speech.
+const SPEECH_BAND_HZ: (f32, f32) = (300.0, 3400.0);
+/// Smoothing factor for the noise floor's exponential moving average -
+/// small, so a few frames of louder non-speech noise don't snap the floor
+/// up all at once.
+const NOISE_FLOOR_ALPHA: f32 = 0.05;
+/// A frame counts as speech once its band energy exceeds the noise floor by
+/// this factor.
+const SPEECH_FACTOR: f32 = 3.0;
+/// Trailing frames kept as speech after band energy drops back below
+/// threshold, ~200 ms at this frame step/sample rate, so word endings
+/// aren't clipped.
+const HANGOVER_FRAMES: usize = 13;
+/// Segments shorter than this are dropped as spurious.
+const MIN_SEGMENT_SECS: f64 = 0.1;
+
+fn hann_window(n: usize) -> Vec<f32> {
+    (0..n)
+        .map(|i| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (n as f32 - 1.0)).cos())
+        .collect()
+}
+
+/// Sums power-spectrum bins whose frequency falls in `SPEECH_BAND_HZ`.
+fn band_energy(power: &[f32], sample_rate: u32, frame_size: usize) -> f32 {
+    let bin_hz = sample_rate as f32 / frame_size as f32;
+    power
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| {
+            let freq = *i as f32 * bin_hz;
+            freq >= SPEECH_BAND_HZ.0 && freq <= SPEECH_BAND_HZ.1
+        })
+        .map(|(_, p)| p)
+        .sum()
+}
+
+/// Detects speech segments, as `(start_sec, end_sec)` pairs, in the mono WAV
+/// at `path`. Splits the signal into overlapping Hann-windowed frames, sums
+/// power in the speech band per frame, and flags a frame as speech once its
+/// band energy exceeds an adaptively tracked noise floor by `SPEECH_FACTOR`.
+/// A hangover keeps a few trailing frames marked as speech after energy
+/// drops, and adjacent speech frames are merged into segments before short
+/// ones are dropped.
+pub fn detect_speech_segments(path: &str) -> anyhow::Result<Vec<(f64, f64)>> {
+    let mut reader = hound::WavReader::open(path)?;
+    let sample_rate = reader.spec().sample_rate;
+
+    let samples: Vec<f32> = reader
+        .samples::<i16>()
+        .map(|s| s.map(|v| v as f32 / 32768.0))
+        .collect::<Result<_, _>>()?;
+
+    if samples.len() < FRAME_SIZE {
+        return Ok(Vec::new());
+    }
+
+    let window = hann_window(FRAME_SIZE);
+    let mut planner = RealFftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(FRAME_SIZE);
+    let mut spectrum: Vec<Complex32> = fft.make_output_vec();
+
+    // `f32::MAX` marks "not yet initialized" so the very first (necessarily
+    // non-speech-by-default) frame seeds the floor directly instead of being
+    // averaged against an arbitrary starting value.
+    let mut noise_floor = f32::MAX;
+    let mut is_speech = Vec::new();
+
+    let mut pos = 0;
+    while pos + FRAME_SIZE <= samples.len() {
+        let mut frame: Vec<f32> = samples[pos..pos + FRAME_SIZE]
+            .iter()
+            .zip(&window)
+            .map(|(s, w)| s * w)
+            .collect();
+
+        fft.process(&mut frame, &mut spectrum)?;
+        let power: Vec<f32> = spectrum.iter().map(|c| c.norm_sqr()).collect();
+        let energy = band_energy(&power, sample_rate, FRAME_SIZE);
+
+        let speech = energy > noise_floor * SPEECH_FACTOR;
+        is_speech.push(speech);
+
+        // Only low-energy frames update the floor, so a run of speech never
+        // drags it up and desensitizes detection to the next quiet talker.
+        if !speech {
+            noise_floor = if noise_floor == f32::MAX {
+                energy
+            } else {
+                noise_floor * (1.0 - NOISE_FLOOR_ALPHA) + energy * NOISE_FLOOR_ALPHA
+            };
+        }
+
+        pos += FRAME_STEP;
+    }
+
+    let mut hangover = vec![false; is_speech.len()];
+    let mut remaining = 0usize;
+    for (i, &speech) in is_speech.iter().enumerate() {
+        if speech {
+            remaining = HANGOVER_FRAMES;
+            hangover[i] = true;
+        } else if remaining > 0 {
+            remaining -= 1;
+            hangover[i] = true;
+        }
+    }
+
+    let frame_secs = FRAME_STEP as f64 / sample_rate as f64;
+    let mut segments = Vec::new();
+    let mut seg_start: Option<usize> = None;
+    for (i, &speech) in hangover.iter().enumerate() {
+        match (speech, seg_start) {
+            (true, None) => seg_start = Some(i),
+            (false, Some(start)) => {
+                push_segment(&mut segments, start, i, frame_secs);
+                seg_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = seg_start {
+        push_segment(&mut segments, start, hangover.len(), frame_secs);
+    }
+
+    Ok(segments)
+}
+
+fn push_segment(segments: &mut Vec<(f64, f64)>, start_frame: usize, end_frame: usize, frame_secs: f64) {
+    let start_sec = start_frame as f64 * frame_secs;
+    let end_sec = end_frame as f64 * frame_secs;
+    if end_sec - start_sec >= MIN_SEGMENT_SECS {
+        segments.push((start_sec, end_sec));
+    }
+}
+
+/// Writes a copy of the WAV at `path` containing only its detected speech
+/// segments, as `{stem}_trimmed.wav` next to the original, and returns that
+/// path. Falls back to the original path, unchanged, if no speech was
+/// detected - an empty trimmed file wouldn't be useful for transcription.
+pub fn trim_silence(path: &str) -> anyhow::Result<PathBuf> {
+    let segments = detect_speech_segments(path)?;
+    if segments.is_empty() {
+        return Ok(PathBuf::from(path));
+    }
+
+    let mut reader = hound::WavReader::open(path)?;
+    let spec = reader.spec();
+    let samples: Vec<i16> = reader.samples::<i16>().collect::<Result<_, _>>()?;
+
+    let input_path = PathBuf::from(path);
+    let output_path = input_path.with_file_name(format!(
+        "{}_trimmed.wav",
+        input_path.file_stem().and_then(|s| s.to_str()).unwrap_or("output")
+    ));
+
+    let mut writer = hound::WavWriter::create(&output_path, spec)?;
+    for (start_sec, end_sec) in &segments {
+        let start_idx = ((*start_sec * spec.sample_rate as f64) as usize).min(samples.len());
+        let end_idx = ((*end_sec * spec.sample_rate as f64) as usize).min(samples.len());
+        for &sample in &samples[start_idx..end_idx] {
+            writer.write_sample(sample)?;
+        }
+    }
+    writer.finalize()?;
+
+    Ok(output_path)
+}
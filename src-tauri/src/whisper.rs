@@ -1,31 +1,80 @@
 use whisper_rs::{WhisperContext, WhisperContextParameters, FullParams, SamplingStrategy};
 use std::path::Path;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::{Mutex, Semaphore};
 use hound::WavReader;
+use serde::Serialize;
+
+use crate::vad;
+
+/// Emitted as `"transcribe-progress"` while a channel is being transcribed,
+/// so the UI can show "Me" and "Them" advancing independently during a dual
+/// recording.
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscribeProgressEvent {
+    pub channel: String,
+    pub segments_done: u32,
+    pub total_segments: u32,
+    pub percent: f32,
+}
 
 pub struct WhisperTranscriber {
-    context: Arc<Mutex<Option<WhisperContext>>>,
+    // The context itself is only mutated while (re)loading the model; once
+    // loaded it's read-only, so callers clone the `Arc<WhisperContext>` out
+    // from under a briefly-held lock and run inference without holding it,
+    // letting two channels transcribe concurrently.
+    context: Arc<Mutex<Option<Arc<WhisperContext>>>>,
     model_path: String,
+    trim_silence: Arc<AtomicBool>,
+    // Swapped wholesale (not resized) when the configured limit changes;
+    // existing in-flight permits are unaffected since each holds its own
+    // Arc to the semaphore it was acquired from.
+    max_parallel: Arc<Mutex<Arc<Semaphore>>>,
 }
 
 impl WhisperTranscriber {
     pub fn new() -> anyhow::Result<Self> {
         let model_path = Self::get_default_model_path()?;
-        
+
         Ok(WhisperTranscriber {
             context: Arc::new(Mutex::new(None)),
             model_path,
+            trim_silence: Arc::new(AtomicBool::new(true)),
+            max_parallel: Arc::new(Mutex::new(Arc::new(Semaphore::new(2)))),
         })
     }
-    
+
     pub fn new_with_model(model_path: &str) -> anyhow::Result<Self> {
         Ok(WhisperTranscriber {
             context: Arc::new(Mutex::new(None)),
             model_path: model_path.to_string(),
+            trim_silence: Arc::new(AtomicBool::new(true)),
+            max_parallel: Arc::new(Mutex::new(Arc::new(Semaphore::new(2)))),
         })
     }
-    
+
+    /// Configures whether long silent spans are dropped from the audio before
+    /// it's handed to whisper, via `vad`'s FFT-based speech detection.
+    /// Reducing hallucinated text and transcription time on sparse meetings
+    /// is the point.
+    pub fn set_silence_trimming(&self, enabled: bool) {
+        self.trim_silence.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Caps how many transcriptions (e.g. the mic/system channels of a dual
+    /// recording) may run at once, so a future batch-transcribe feature can't
+    /// oversubscribe the machine.
+    pub async fn set_max_parallel_transcriptions(&self, max_parallel: usize) {
+        let mut guard = self.max_parallel.lock().await;
+        *guard = Arc::new(Semaphore::new(max_parallel.max(1)));
+    }
+
+    async fn acquire_permit(&self) -> tokio::sync::OwnedSemaphorePermit {
+        let semaphore = { self.max_parallel.lock().await.clone() };
+        semaphore.acquire_owned().await.expect("transcription semaphore should never be closed")
+    }
+
     fn get_default_model_path() -> anyhow::Result<String> {
         // Check common model locations
         let possible_paths = vec![
@@ -51,7 +100,7 @@ impl WhisperTranscriber {
     
     async fn ensure_context_loaded(&self) -> anyhow::Result<()> {
         let mut ctx = self.context.lock().await;
-        
+
         if ctx.is_none() {
             if !Path::new(&self.model_path).exists() {
                 return Err(anyhow::anyhow!(
@@ -59,83 +108,85 @@ impl WhisperTranscriber {
                     self.model_path
                 ));
             }
-            
+
             let ctx_params = WhisperContextParameters::default();
             let whisper_ctx = WhisperContext::new_with_params(&self.model_path, ctx_params)
                 .map_err(|e| anyhow::anyhow!("Failed to load Whisper model: {:?}", e))?;
-            
-            *ctx = Some(whisper_ctx);
+
+            *ctx = Some(Arc::new(whisper_ctx));
         }
-        
+
         Ok(())
     }
-    
+
     pub async fn transcribe(&self, audio_path: &str) -> anyhow::Result<String> {
+        self.transcribe_inner(audio_path, None).await
+    }
+
+    /// Same as `transcribe`, but emits `"transcribe-progress"` events labeled
+    /// with `channel` as segments come in, so a dual recording's "Me" and
+    /// "Them" channels can be shown advancing independently. Only holds the
+    /// context lock long enough to clone the `Arc`, so two channels can run
+    /// this concurrently (gated by `max_parallel_transcriptions`).
+    pub async fn transcribe_with_progress(
+        &self,
+        audio_path: &str,
+        channel: &str,
+        app_handle: tauri::AppHandle,
+    ) -> anyhow::Result<String> {
+        self.transcribe_inner(audio_path, Some((channel.to_string(), app_handle))).await
+    }
+
+    async fn transcribe_inner(
+        &self,
+        audio_path: &str,
+        progress: Option<(String, tauri::AppHandle)>,
+    ) -> anyhow::Result<String> {
+        let _permit = self.acquire_permit().await;
+
         self.ensure_context_loaded().await?;
-        
+
+        // Drop dead air before transcription via the FFT-based VAD, so a
+        // sparse meeting doesn't cost whisper time (or hallucinated text) on
+        // silence. Falls back to the original file if trimming fails.
+        let source_path = if self.trim_silence.load(Ordering::Relaxed) {
+            match vad::trim_silence(audio_path) {
+                Ok(path) => path,
+                Err(e) => {
+                    eprintln!("Voice-activity trimming failed, transcribing original audio: {}", e);
+                    std::path::PathBuf::from(audio_path)
+                }
+            }
+        } else {
+            std::path::PathBuf::from(audio_path)
+        };
+
         // Read and preprocess audio
-        let audio_data = self.load_audio(audio_path)?;
-        
-        let ctx = self.context.lock().await;
-        let whisper_ctx = ctx.as_ref().ok_or_else(|| anyhow::anyhow!("Whisper context not loaded"))?;
-
-        // Create a state for this transcription
-        let mut state = whisper_ctx.create_state()
-            .map_err(|e| anyhow::anyhow!("Failed to create whisper state: {:?}", e))?;
-
-        // Set up transcription parameters
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
-        params.set_n_threads(4);
-        params.set_translate(false);
-        params.set_language(Some("en"));
-        params.set_print_special(false);
-        params.set_print_progress(false);
-        params.set_print_realtime(false);
-        params.set_print_timestamps(true);
-
-        // Run transcription
-        state.full(params, &audio_data)
-            .map_err(|e| anyhow::anyhow!("Transcription failed: {:?}", e))?;
-
-        // Extract results
-        let num_segments = state.full_n_segments()
-            .map_err(|e| anyhow::anyhow!("Failed to get segment count: {:?}", e))?;
-
-        let mut transcript = String::new();
-
-        for i in 0..num_segments {
-            let segment = state.full_get_segment_text(i)
-                .map_err(|e| anyhow::anyhow!("Failed to get segment text: {:?}", e))?;
-
-            let start = state.full_get_segment_t0(i)
-                .map_err(|e| anyhow::anyhow!("Failed to get segment start: {:?}", e))?;
-
-            let end = state.full_get_segment_t1(i)
-                .map_err(|e| anyhow::anyhow!("Failed to get segment end: {:?}", e))?;
-            
-            // Format timestamp as [MM:SS.mmm]
-            let start_secs = start as f64 / 100.0;
-            let end_secs = end as f64 / 100.0;
-            
-            let start_mins = (start_secs / 60.0) as i32;
-            let start_secs_rem = start_secs % 60.0;
-            let start_ms = ((start_secs_rem - start_secs_rem.floor()) * 1000.0) as i32;
-            
-            transcript.push_str(&format!(
-                "[{:02}:{:02}.{:03}] - [{:02}:{:02}.{:03}] {}\n",
-                start_mins,
-                start_secs_rem as i32,
-                start_ms,
-                (end_secs / 60.0) as i32,
-                (end_secs % 60.0) as i32,
-                ((end_secs % 1.0) * 1000.0) as i32,
-                segment.trim()
-            ));
+        let audio_data = self.load_audio(source_path.to_string_lossy().as_ref());
+
+        // The trimmed copy is a scratch file generated solely for this call;
+        // clean it up now rather than leaking one full extra WAV per
+        // transcription, regardless of whether decoding succeeded.
+        if source_path != std::path::Path::new(audio_path) {
+            if let Err(e) = std::fs::remove_file(&source_path) {
+                eprintln!("Failed to remove trimmed audio file {}: {}", source_path.display(), e);
+            }
         }
-        
-        Ok(transcript.trim().to_string())
+
+        let audio_data = audio_data?;
+
+        let whisper_ctx = {
+            let ctx = self.context.lock().await;
+            ctx.clone().ok_or_else(|| anyhow::anyhow!("Whisper context not loaded"))?
+        };
+
+        // Run the actual inference off the async runtime's threads; whisper
+        // is CPU-bound and blocks for the whole clip.
+        tokio::task::spawn_blocking(move || run_inference(&whisper_ctx, &audio_data, progress))
+            .await
+            .map_err(|e| anyhow::anyhow!("Transcription task panicked: {}", e))?
     }
-    
+
     fn load_audio(&self, audio_path: &str) -> anyhow::Result<Vec<f32>> {
         let mut reader = WavReader::open(audio_path)?;
         let spec = reader.spec();
@@ -173,19 +224,6 @@ impl WhisperTranscriber {
         }
     }
     
-    pub async fn transcribe_with_progress<F>(
-        &self,
-        audio_path: &str,
-        _progress_callback: F,
-    ) -> anyhow::Result<String>
-    where
-        F: Fn(f32) + Send + 'static,
-    {
-        // For now, just call the regular transcribe method
-        // In the future, this could use a callback for progress updates
-        self.transcribe(audio_path).await
-    }
-    
     pub fn is_model_available(&self) -> bool {
         Path::new(&self.model_path).exists()
     }
@@ -197,21 +235,190 @@ impl WhisperTranscriber {
     pub async fn set_model(&self, model_path: &str) -> anyhow::Result<()> {
         let mut ctx = self.context.lock().await;
         *ctx = None; // Force reload with new model
-        
+
         if !Path::new(model_path).exists() {
             return Err(anyhow::anyhow!("Model file not found: {}", model_path));
         }
-        
+
         let ctx_params = WhisperContextParameters::default();
         let whisper_ctx = WhisperContext::new_with_params(model_path, ctx_params)
             .map_err(|e| anyhow::anyhow!("Failed to load Whisper model: {:?}", e))?;
-        
-        *ctx = Some(whisper_ctx);
-        
+
+        *ctx = Some(Arc::new(whisper_ctx));
+
+        Ok(())
+    }
+
+    /// Transcribes `audio_path` and writes the result to `output_path` in
+    /// `format`, for downstream tooling (video editors, subtitle players)
+    /// that expects one of these standard container formats rather than
+    /// WizScribe's own `[MM:SS.mmm]` string.
+    pub async fn transcribe_to(
+        &self,
+        audio_path: &str,
+        output_path: &str,
+        format: OutputFormat,
+    ) -> anyhow::Result<()> {
+        let transcript = self.transcribe(audio_path).await?;
+        let segments = parse_transcript_to_segments(&transcript);
+
+        let rendered = match format {
+            OutputFormat::Srt => segments_to_srt(&segments),
+            OutputFormat::Vtt => segments_to_vtt(&segments),
+            OutputFormat::Csv => segments_to_csv(&segments),
+        };
+
+        tokio::fs::write(output_path, rendered).await?;
+
         Ok(())
     }
 }
 
+/// Subtitle/export container formats `transcribe_to` can write. Mirrors
+/// whisper.cpp's own `output_srt`/`output_vtt`/`output_csv` CLI modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Srt,
+    Vtt,
+    Csv,
+}
+
+/// Renders `ms` as `HH:MM:SS,mmm` (SubRip) or, with `vtt: true`, as
+/// `HH:MM:SS.mmm` (WebVTT).
+fn format_timestamp(ms: i64, vtt: bool) -> String {
+    let ms = ms.max(0);
+    let hours = ms / 3_600_000;
+    let minutes = (ms % 3_600_000) / 60_000;
+    let secs = (ms % 60_000) / 1000;
+    let millis = ms % 1000;
+    let sep = if vtt { "." } else { "," };
+    format!("{:02}:{:02}:{:02}{}{:03}", hours, minutes, secs, sep, millis)
+}
+
+/// Renders `segments` as SubRip (`.srt`): a 1-based cue index, a
+/// `start --> end` line using a `,` millisecond separator, the cue text,
+/// then a blank line.
+pub fn segments_to_srt(segments: &[TranscriptionSegment]) -> String {
+    let mut out = String::new();
+
+    for (i, seg) in segments.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_timestamp(seg.start_ms, false),
+            format_timestamp(seg.end_ms, false),
+            seg.text.trim()
+        ));
+    }
+
+    out.trim_end().to_string()
+}
+
+/// Renders `segments` as WebVTT (`.vtt`): the mandatory `WEBVTT` header
+/// followed by cues using a `.` millisecond separator.
+pub fn segments_to_vtt(segments: &[TranscriptionSegment]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+
+    for seg in segments {
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            format_timestamp(seg.start_ms, true),
+            format_timestamp(seg.end_ms, true),
+            seg.text.trim()
+        ));
+    }
+
+    out.trim_end().to_string()
+}
+
+/// Renders `segments` as CSV with a `start_ms,end_ms,text` header row. Text
+/// is quoted and internal quotes doubled per RFC 4180 so embedded commas
+/// and quotes survive a round trip through spreadsheet tools.
+pub fn segments_to_csv(segments: &[TranscriptionSegment]) -> String {
+    let mut out = String::from("start_ms,end_ms,text\n");
+
+    for seg in segments {
+        let escaped_text = seg.text.trim().replace('"', "\"\"");
+        out.push_str(&format!("{},{},\"{}\"\n", seg.start_ms, seg.end_ms, escaped_text));
+    }
+
+    out.trim_end().to_string()
+}
+
+/// Runs the blocking whisper inference and formats the transcript. When
+/// `progress` is set, emits a `"transcribe-progress"` event after each
+/// segment is extracted so the frontend can track the named channel.
+fn run_inference(
+    whisper_ctx: &WhisperContext,
+    audio_data: &[f32],
+    progress: Option<(String, tauri::AppHandle)>,
+) -> anyhow::Result<String> {
+    use tauri::Emitter;
+
+    let mut state = whisper_ctx.create_state()
+        .map_err(|e| anyhow::anyhow!("Failed to create whisper state: {:?}", e))?;
+
+    let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+    params.set_n_threads(4);
+    params.set_translate(false);
+    params.set_language(Some("en"));
+    params.set_print_special(false);
+    params.set_print_progress(false);
+    params.set_print_realtime(false);
+    params.set_print_timestamps(true);
+
+    state.full(params, audio_data)
+        .map_err(|e| anyhow::anyhow!("Transcription failed: {:?}", e))?;
+
+    let num_segments = state.full_n_segments()
+        .map_err(|e| anyhow::anyhow!("Failed to get segment count: {:?}", e))?;
+
+    let mut transcript = String::new();
+
+    for i in 0..num_segments {
+        let segment = state.full_get_segment_text(i)
+            .map_err(|e| anyhow::anyhow!("Failed to get segment text: {:?}", e))?;
+
+        let start = state.full_get_segment_t0(i)
+            .map_err(|e| anyhow::anyhow!("Failed to get segment start: {:?}", e))?;
+
+        let end = state.full_get_segment_t1(i)
+            .map_err(|e| anyhow::anyhow!("Failed to get segment end: {:?}", e))?;
+
+        // Format timestamp as [MM:SS.mmm]
+        let start_secs = start as f64 / 100.0;
+        let end_secs = end as f64 / 100.0;
+
+        let start_mins = (start_secs / 60.0) as i32;
+        let start_secs_rem = start_secs % 60.0;
+        let start_ms = ((start_secs_rem - start_secs_rem.floor()) * 1000.0) as i32;
+
+        transcript.push_str(&format!(
+            "[{:02}:{:02}.{:03}] - [{:02}:{:02}.{:03}] {}\n",
+            start_mins,
+            start_secs_rem as i32,
+            start_ms,
+            (end_secs / 60.0) as i32,
+            (end_secs % 60.0) as i32,
+            ((end_secs % 1.0) * 1000.0) as i32,
+            segment.trim()
+        ));
+
+        if let Some((channel, app_handle)) = &progress {
+            let segments_done = (i + 1) as u32;
+            let _ = app_handle.emit("transcribe-progress", TranscribeProgressEvent {
+                channel: channel.clone(),
+                segments_done,
+                total_segments: num_segments as u32,
+                percent: segments_done as f32 / num_segments.max(1) as f32 * 100.0,
+            });
+        }
+    }
+
+    Ok(transcript.trim().to_string())
+}
+
 #[derive(Debug, Clone)]
 pub struct TranscriptionSegment {
     pub start_ms: i64,